@@ -0,0 +1,102 @@
+//! Counting the memory each part allocates, when built with the
+//! `count-allocations` feature. Without it, nothing is counted and the normal
+//! allocator is left alone.
+
+/// How much a part allocated while it was solved.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// How many times memory was allocated or reallocated
+    pub count: u64,
+    /// The total size of every allocation and reallocation
+    pub bytes: u64,
+    /// The most memory allocated but not yet freed at any one time, not
+    /// counting anything allocated beforehand
+    pub peak: u64,
+}
+
+/// Runs `f`, counting what it allocates on this thread if allocations are
+/// being counted at all.
+#[cfg(not(feature = "count-allocations"))]
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Allocations>) {
+    (f(), None)
+}
+
+#[cfg(feature = "count-allocations")]
+pub use counting::measure;
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::Allocations;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    // Counted per thread, so that parts running on other threads don't count
+    // towards each other. Memory freed on a different thread from the one
+    // that allocated it can make the live total dip below where it started.
+    thread_local! {
+        static COUNT: Cell<u64> = const { Cell::new(0) };
+        static BYTES: Cell<u64> = const { Cell::new(0) };
+        static LIVE: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+    }
+
+    /// The system allocator, keeping count of what it's asked for.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            allocated(layout.size());
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            allocated(layout.size());
+            unsafe { System.alloc_zeroed(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            freed(layout.size());
+            unsafe { System.dealloc(ptr, layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            freed(layout.size());
+            allocated(new_size);
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    fn allocated(size: usize) {
+        // The thread's counters may already be gone while it shuts down, and
+        // there's nothing to measure by then anyway
+        let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+        let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + size as i64);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+    }
+
+    fn freed(size: usize) {
+        let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+    }
+
+    /// Runs `f`, counting what it allocates on this thread.
+    pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Allocations>) {
+        let (count, bytes, live) = (COUNT.get(), BYTES.get(), LIVE.get());
+        PEAK.set(live);
+        let result = f();
+        let allocations = Allocations {
+            count: COUNT.get() - count,
+            bytes: BYTES.get() - bytes,
+            peak: (PEAK.get() - live).max(0) as u64,
+        };
+        (result, Some(allocations))
+    }
+}
@@ -0,0 +1,129 @@
+//! Answers produced by solving a part.
+
+use std::{borrow::Cow, fmt, str::FromStr};
+
+/// The result of solving one part of a day, along with a human-readable
+/// description of what the value means.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    label: Cow<'static, str>,
+    value: Value,
+    implemented: bool,
+}
+
+impl Answer {
+    /// Labels `value`, e.g. `Answer::new("Total winnings", 6440)`.
+    pub fn new(label: impl Into<Cow<'static, str>>, value: impl Into<Value>) -> Self {
+        Answer {
+            label: label.into(),
+            value: value.into(),
+            implemented: true,
+        }
+    }
+
+    /// Stands in for the answer to a part that hasn't been solved yet, which
+    /// the runner reports as not implemented.
+    pub fn not_implemented() -> Self {
+        Answer {
+            label: Cow::Borrowed("Not implemented"),
+            value: Value::Text(String::new()),
+            implemented: false,
+        }
+    }
+
+    /// Whether this is a real answer, and not
+    /// [`not_implemented`](Answer::not_implemented)'s stand-in.
+    pub fn is_implemented(&self) -> bool {
+        self.implemented
+    }
+
+    /// What the value means, e.g. "Total winnings".
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The value to submit.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.label, self.value)
+    }
+}
+
+/// The value of an answer, in the narrowest type that holds it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Value {
+    /// Any integer that fits in an `i64`
+    Int(i64),
+    /// An integer too large for an `i64`
+    Big(i128),
+    /// Anything that isn't an integer
+    Text(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{n}"),
+            Value::Big(n) => write!(f, "{n}"),
+            Value::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = std::convert::Infallible;
+
+    /// Parses the narrowest value that can hold `s`, falling back to text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(n) = str::parse::<i64>(s) {
+            Ok(Value::Int(n))
+        } else if let Ok(n) = str::parse::<i128>(s) {
+            Ok(Value::Big(n))
+        } else {
+            Ok(Value::Text(s.to_string()))
+        }
+    }
+}
+
+macro_rules! value_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Value::Int(n),
+                        Err(_) => Value::Big(n as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+value_from_int!(i32, u32, i64, u64, usize);
+
+impl From<i128> for Value {
+    fn from(n: i128) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Value::Int(n),
+            Err(_) => Value::Big(n),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_string())
+    }
+}
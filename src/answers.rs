@@ -0,0 +1,127 @@
+//! The file of known-correct answers used to catch regressions.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answer::Value, error::Error, input::InputSource, runner::Outcome, Part};
+
+/// Known-correct answers for each year, day, part and input file.
+pub struct Answers {
+    path: PathBuf,
+    file: AnswersFile,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct AnswersFile {
+    #[serde(default, rename = "answer")]
+    entries: Vec<Entry>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Entry {
+    year: u16,
+    day: u8,
+    part: Part,
+    input: String,
+    answer: String,
+}
+
+impl Entry {
+    fn matches(&self, year: u16, day: u8, part: &Part, input: &str) -> bool {
+        self.year == year && self.day == day && self.part == *part && self.input == input
+    }
+}
+
+/// How an answer compared with the recorded one.
+pub enum Check {
+    /// The answer matched
+    Pass,
+    /// The answer didn't match this recorded one
+    Fail(Value),
+    /// No answer has been recorded for this input
+    Missing,
+}
+
+impl Answers {
+    /// Loads the answers at `path`, or an empty set if it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file = match std::fs::read_to_string(path) {
+            Ok(text) => toml::from_str::<AnswersFile>(&text)
+                .map_err(|e| Error::Answers(path.display().to_string(), e.to_string()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => AnswersFile::default(),
+            Err(e) => return Err(Error::Input(path.display().to_string(), e)),
+        };
+        Ok(Answers {
+            path: path.to_path_buf(),
+            file,
+        })
+    }
+
+    /// The recorded answer for `part` of `day` in `year` with the given input
+    /// file.
+    pub fn expected(&self, year: u16, day: u8, part: &Part, input: &str) -> Option<Value> {
+        self.file
+            .entries
+            .iter()
+            .find(|entry| entry.matches(year, day, part, input))
+            .map(|entry| str::parse::<Value>(&entry.answer).unwrap())
+    }
+
+    /// Compares `answer` with the recorded one.
+    pub fn check(&self, year: u16, day: u8, part: &Part, input: &str, answer: &Value) -> Check {
+        match self.expected(year, day, part, input) {
+            Some(expected) if expected == *answer => Check::Pass,
+            Some(expected) => Check::Fail(expected),
+            None => Check::Missing,
+        }
+    }
+
+    /// Records `answer` as correct, replacing any earlier answer.
+    pub fn record(&mut self, year: u16, day: u8, part: &Part, input: &str, answer: &Value) {
+        self.file
+            .entries
+            .retain(|entry| !entry.matches(year, day, part, input));
+        self.file.entries.push(Entry {
+            year,
+            day,
+            part: part.clone(),
+            input: input.to_string(),
+            answer: answer.to_string(),
+        });
+    }
+
+    /// Writes the answers back to the file they were loaded from.
+    pub fn save(&mut self) -> Result<(), Error> {
+        self.file.entries.sort_by(|a, b| {
+            (a.year, a.day, &a.input, &a.part).cmp(&(b.year, b.day, &b.input, &b.part))
+        });
+        let text = toml::to_string(&self.file)
+            .map_err(|e| Error::Answers(self.path.display().to_string(), e.to_string()))?;
+        std::fs::write(&self.path, text)
+            .map_err(|e| Error::Write(self.path.display().to_string(), e))
+    }
+}
+
+/// Pairs each outcome with its input and how it compared with the answers
+/// file at `path`, if it has an answer to compare.
+pub fn compare(
+    outcomes: Vec<Outcome>,
+    year: u16,
+    source: &InputSource,
+    path: &Path,
+) -> Result<Vec<(Outcome, String, Option<Check>)>, Error> {
+    let answers = Answers::load(path)?;
+    let mut checks = Vec::new();
+    for outcome in outcomes {
+        let input = source.key(outcome.day).ok_or(Error::StdinAnswers)?;
+        let check = match &outcome.answer {
+            Ok(answer) => {
+                Some(answers.check(year, outcome.day, &outcome.part, &input, answer.value()))
+            }
+            Err(_) => None,
+        };
+        checks.push((outcome, input, check));
+    }
+    Ok(checks)
+}
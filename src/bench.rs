@@ -0,0 +1,114 @@
+//! Timing days repeatedly for more reliable measurements.
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    allocations::{self, Allocations},
+    error::Error,
+    input::{InputSource, RawInput},
+    runner::{self, DaySelection, PartSelection},
+    solver::Registry,
+    Part,
+};
+
+/// Summary statistics over repeated timings of the same work.
+pub struct Stats {
+    /// The fastest sample
+    pub min: Duration,
+    /// The middle sample
+    pub median: Duration,
+    /// The average sample
+    pub mean: Duration,
+    /// The sample that 95% of samples were no slower than
+    pub p95: Duration,
+    /// What the last sample allocated, if allocations are being counted.
+    /// Every sample does the same work, so they allocate alike
+    pub allocations: Option<Allocations>,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>, allocations: Option<Allocations>) -> Self {
+        samples.sort();
+        let n = samples.len();
+        // Nearest-rank percentile
+        let p95_rank = (n * 95).div_ceil(100);
+        Stats {
+            min: samples[0],
+            median: samples[n / 2],
+            mean: samples.iter().sum::<Duration>() / n as u32,
+            p95: samples[p95_rank.max(1) - 1],
+            allocations,
+        }
+    }
+}
+
+/// Timings for a single part of a single day, with parsing and solving
+/// measured separately.
+pub struct Benchmark {
+    /// The day that was timed
+    pub day: u8,
+    /// The part that was timed
+    pub part: Part,
+    /// Parse and solve timings, or why the part couldn't be timed
+    pub timings: Result<(Stats, Stats), Error>,
+}
+
+/// Runs each selected part once to warm up, then `samples` more times. Only
+/// the warm-up is subject to `timeout`.
+pub fn bench_selection(
+    registry: &Registry,
+    days: &DaySelection,
+    parts: &PartSelection,
+    source: &InputSource,
+    samples: u32,
+    timeout: Option<Duration>,
+) -> Vec<Benchmark> {
+    let mut benchmarks = Vec::new();
+    for &day in days.days() {
+        for part in parts.parts() {
+            let timings = bench_part(registry, day, &part, source, samples, timeout);
+            benchmarks.push(Benchmark { day, part, timings });
+        }
+    }
+    benchmarks
+}
+
+fn bench_part(
+    registry: &Registry,
+    day: u8,
+    part: &Part,
+    source: &InputSource,
+    samples: u32,
+    timeout: Option<Duration>,
+) -> Result<(Stats, Stats), Error> {
+    // The warm-up goes through the runner so that a bad input, panicking solver
+    // or one that doesn't finish in time is reported instead of being timed
+    runner::run_part(registry, day, part, source, timeout)?;
+
+    let solver = registry.get(day)?;
+    // Inputs are read up front so that it's parsing that's timed, not the disk
+    let lines = source.read(day)?;
+    let name = source.name(day);
+    let mut parse_times = Vec::with_capacity(samples as usize);
+    let mut solve_times = Vec::with_capacity(samples as usize);
+    let (mut parse_allocations, mut solve_allocations) = (None, None);
+    for _ in 0..samples {
+        let input = RawInput::from_lines(lines.clone(), name.as_str());
+
+        let before = Instant::now();
+        let (input, allocations) = allocations::measure(|| solver.parse(input));
+        parse_times.push(before.elapsed());
+        let input = input?;
+        parse_allocations = allocations;
+
+        let before = Instant::now();
+        let (answer, allocations) = allocations::measure(|| solver.solve(input.as_ref(), part));
+        solve_times.push(before.elapsed());
+        std::hint::black_box(answer);
+        solve_allocations = allocations;
+    }
+    Ok((
+        Stats::new(parse_times, parse_allocations),
+        Stats::new(solve_times, solve_allocations),
+    ))
+}
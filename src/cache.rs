@@ -0,0 +1,174 @@
+//! Answers remembered from earlier runs, so that parts whose input and solver
+//! haven't changed don't have to be solved again.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use crate::{
+    answer::{Answer, Value},
+    error::Error,
+    input::RawInput,
+    tsv, Part,
+};
+
+/// Cached answers, kept next to the inputs as tab separated `day`, `part`,
+/// input hash, solver version, label and answer lines. The cache can be shared
+/// between the threads running parts, and is only written back by
+/// [`save`](Cache::save).
+pub struct Cache {
+    path: PathBuf,
+    entries: Mutex<Vec<Entry>>,
+    changed: AtomicBool,
+}
+
+struct Entry {
+    key: Key,
+    answer: Answer,
+}
+
+/// What a cached answer depends on.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key {
+    day: u8,
+    part: Part,
+    input: u64,
+    version: u32,
+}
+
+impl Key {
+    /// Identifies the answer to `part` of `day` for this input, as solved by
+    /// version `version` of the day's solver. The input is read a line at a
+    /// time, and any error reading it is left in `input`.
+    pub fn new(day: u8, part: &Part, input: &mut RawInput, version: u32) -> Self {
+        Key {
+            day,
+            part: part.clone(),
+            input: hash(input),
+            version,
+        }
+    }
+}
+
+impl Cache {
+    /// Where the cache for the inputs in `input_dir` is kept.
+    pub fn path(input_dir: &Path) -> PathBuf {
+        input_dir.join("cache.tsv")
+    }
+
+    /// Loads the cache kept in `input_dir`, which is empty if nothing has been
+    /// cached yet. Lines that can't be read are dropped, as they can always be
+    /// solved again.
+    pub fn load(input_dir: &Path) -> Result<Self, Error> {
+        let path = Cache::path(input_dir);
+        let entries = tsv::load(&path, |fields| {
+            let day = str::parse::<u8>(fields.next()?).ok()?;
+            let part = Part::from_level(str::parse::<u8>(fields.next()?).ok()?)?;
+            let input = u64::from_str_radix(fields.next()?, 16).ok()?;
+            let version = str::parse::<u32>(fields.next()?).ok()?;
+            let label = fields.next()?.to_string();
+            let value = str::parse::<Value>(fields.next()?).ok()?;
+            Some(Entry {
+                key: Key {
+                    day,
+                    part,
+                    input,
+                    version,
+                },
+                answer: Answer::new(label, value),
+            })
+        })?;
+        Ok(Cache {
+            path,
+            entries: Mutex::new(entries),
+            changed: AtomicBool::new(false),
+        })
+    }
+
+    /// The cached answer for `key`, if there is one.
+    pub fn get(&self, key: &Key) -> Option<Answer> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|entry| entry.key == *key)
+            .map(|entry| entry.answer.clone())
+    }
+
+    /// Caches `answer`, replacing any answer cached for the same input with an
+    /// older version of the solver. Answers that wouldn't survive being written
+    /// on a single line aren't cached.
+    pub fn insert(&self, key: Key, answer: &Answer) {
+        let fields = [answer.label().to_string(), answer.value().to_string()];
+        if fields
+            .iter()
+            .any(|field| field.contains(['\t', '\n', '\r']))
+        {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|entry| {
+            let old = &entry.key;
+            !(old.day == key.day && old.part == key.part && old.input == key.input)
+        });
+        entries.push(Entry {
+            key,
+            answer: answer.clone(),
+        });
+        self.changed.store(true, Ordering::Relaxed);
+    }
+
+    /// Writes the cache back to its file, if anything was added to it, creating
+    /// its directory if need be.
+    pub fn save(&self) -> Result<(), Error> {
+        if !self.changed.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let mut entries = self.entries.lock().unwrap();
+        entries.sort_by_key(|entry| (entry.key.day, entry.key.part.clone()));
+        let text = entries
+            .iter()
+            .map(|Entry { key, answer }| {
+                format!(
+                    "{}\t{}\t{:016x}\t{}\t{}\t{}\n",
+                    key.day,
+                    key.part.level(),
+                    key.input,
+                    key.version,
+                    answer.label(),
+                    answer.value()
+                )
+            })
+            .collect::<String>();
+        tsv::save(&self.path, &text)
+    }
+}
+
+/// Removes the cache kept in `input_dir`, returning whether there was one.
+pub fn clear(input_dir: &Path) -> Result<bool, Error> {
+    let path = Cache::path(input_dir);
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(Error::Write(path.display().to_string(), e)),
+    }
+}
+
+/// A 64-bit FNV-1a hash of the input's lines. Unlike the standard library's
+/// hasher, it's guaranteed to stay the same between builds, which matters for
+/// a hash that's saved to disk.
+fn hash(input: &mut RawInput) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    let mut lines = input.stream();
+    while let Some(line) = lines.next_line() {
+        for byte in line.text().bytes().chain([b'\n']) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
@@ -0,0 +1,45 @@
+//! Cooperative cancellation for solvers that can run for a long time.
+//!
+//! The runner gives each solver a deadline when `--timeout` is used, and long
+//! loops call [`check`] to give up once it has passed.
+
+use std::{cell::Cell, panic, time::Instant};
+
+/// How many calls to [`check`] go by between looking at the clock, so that it's
+/// cheap enough to call on every iteration of a hot loop.
+const CHECK_INTERVAL: u32 = 1024;
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+    static CALLS: Cell<u32> = const { Cell::new(0) };
+}
+
+/// The panic payload used to unwind out of a solver whose deadline passed.
+pub struct Cancelled;
+
+/// Stops the current solver if it has run past its deadline.
+pub fn check() {
+    let Some(deadline) = DEADLINE.get() else {
+        return;
+    };
+    let calls = CALLS.get().wrapping_add(1);
+    CALLS.set(calls);
+    if calls.is_multiple_of(CHECK_INTERVAL) && Instant::now() >= deadline {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Runs `f` with `deadline` applying to any [`check`] it makes on this thread.
+pub fn with_deadline<R>(deadline: Instant, f: impl FnOnce() -> R) -> R {
+    struct Reset;
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            DEADLINE.set(None);
+        }
+    }
+
+    DEADLINE.set(Some(deadline));
+    CALLS.set(0);
+    let _reset = Reset;
+    f()
+}
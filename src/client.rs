@@ -0,0 +1,125 @@
+//! Requests to the puzzle website.
+
+use std::{
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{answer::Value, error::Error, submit::Verdict, Part};
+
+/// The puzzle website.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/estherlurie/aoc-2023";
+
+/// Talks to the puzzle website on behalf of a logged in user.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// A client for the site at `base_url`, logged in with `session`.
+    pub fn new(base_url: &str, session: String) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// Downloads the logged in user's input for `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| Error::Http(url.clone(), describe(e)))?
+            .into_string()
+            .map_err(|e| Error::Http(url, e.to_string()))
+    }
+
+    /// Posts `answer` for one part of `day` and reports how it was received.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: &Part,
+        answer: &Value,
+    ) -> Result<Verdict, Error> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[
+                ("level", &part.level().to_string()),
+                ("answer", &answer.to_string()),
+            ])
+            .map_err(|e| Error::Http(url.clone(), describe(e)))?
+            .into_string()
+            .map_err(|e| Error::Http(url.clone(), e.to_string()))?;
+        Verdict::from_html(&html)
+            .ok_or_else(|| Error::Http(url, "unrecognized response".to_string()))
+    }
+}
+
+fn describe(e: ureq::Error) -> String {
+    match e {
+        ureq::Error::Status(code, response) => {
+            format!("{code} {}", response.status_text())
+        }
+        ureq::Error::Transport(transport) => match std::error::Error::source(&transport) {
+            Some(source) => format!("{}: {source}", transport.kind()),
+            None => transport.kind().to_string(),
+        },
+    }
+}
+
+/// Finds the session token, preferring the `AOC_SESSION` environment variable
+/// over the `aoc/session` file in the user's config directory.
+pub fn load_session() -> Result<String, Error> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = session_file().ok_or(Error::MissingSession(None))?;
+    match std::fs::read_to_string(&path) {
+        Ok(session) => Ok(session.trim().to_string()),
+        Err(_) => Err(Error::MissingSession(Some(path.display().to_string()))),
+    }
+}
+
+fn session_file() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+/// Whether `day`'s puzzle has been released. Puzzles unlock at midnight EST,
+/// which is 05:00 UTC.
+pub fn is_unlocked(year: u16, day: u8) -> bool {
+    let unlocks_at = days_since_epoch(year as i64, 12, day as i64) * 86400 + 5 * 3600;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    now >= unlocks_at
+}
+
+/// Days from 1970-01-01 to the given date in the proleptic Gregorian calendar.
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    // From Howard Hinnant's `days_from_civil`
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
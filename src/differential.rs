@@ -0,0 +1,111 @@
+//! Differential testing, which runs each day's solutions and its reference
+//! solutions on many small generated inputs to find one they disagree on.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{
+    answer::Value,
+    error::Error,
+    generate,
+    input::RawInput,
+    runner::{self, DaySelection, PartSelection},
+    solver::{Entry, Registry},
+    Part,
+};
+
+/// The first generated input that a part's solution and reference solution
+/// disagreed on.
+pub struct Disagreement {
+    /// The seed the input was generated from
+    pub seed: u64,
+    /// The input itself
+    pub input: Vec<String>,
+    /// What the solution answered, or why it couldn't
+    pub answer: Result<Value, Error>,
+    /// What the reference solution answered, or why it couldn't
+    pub expected: Result<Value, Error>,
+}
+
+/// How a part's solution compared with its reference solution.
+pub struct Comparison {
+    /// The day compared
+    pub day: u8,
+    /// The part compared
+    pub part: Part,
+    /// How many inputs were compared, including any that was disagreed on
+    pub inputs: u64,
+    /// The first disagreement, if there was one, or why the part couldn't be
+    /// compared at all
+    pub result: Result<Option<Disagreement>, Error>,
+}
+
+/// Compares each selected part's solution with its reference solution on the
+/// inputs of roughly `size` generated from each seed below `seeds`, stopping
+/// at the first input they disagree on.
+pub fn compare_selection(
+    registry: &Registry,
+    days: &DaySelection,
+    parts: &PartSelection,
+    seeds: u64,
+    size: usize,
+) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+    for &day in days.days() {
+        for part in parts.parts() {
+            let mut inputs = 0;
+            let result = (0..seeds)
+                .find_map(|seed| {
+                    let result = compare_one(registry, day, &part, seed, size);
+                    if result.is_ok() {
+                        inputs += 1;
+                    }
+                    result.transpose()
+                })
+                .transpose();
+            comparisons.push(Comparison {
+                day,
+                part,
+                inputs,
+                result,
+            });
+        }
+    }
+    comparisons
+}
+
+fn compare_one(
+    registry: &Registry,
+    day: u8,
+    part: &Part,
+    seed: u64,
+    size: usize,
+) -> Result<Option<Disagreement>, Error> {
+    let solver = registry.get(day)?;
+    let input = generate::generate(registry, day, seed, size)?;
+    let expected = match catch_panic(|| solver.solve_reference(&input, part)) {
+        Ok(Some(expected)) => Ok(expected),
+        Ok(None) => return Err(Error::NoReference(day)),
+        Err(e) => Err(e),
+    };
+    let answer = solve(solver, &input, part);
+    match (&answer, &expected) {
+        (Ok(answer), Ok(expected)) if answer == expected => Ok(None),
+        _ => Ok(Some(Disagreement {
+            seed,
+            input,
+            answer,
+            expected,
+        })),
+    }
+}
+
+fn solve(solver: &Entry, input: &[String], part: &Part) -> Result<Value, Error> {
+    let answer =
+        catch_panic(|| solver.run(RawInput::from_lines(input.to_vec(), "<generated>"), part))??;
+    Ok(answer.value().clone())
+}
+
+fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Error> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| Error::Panicked(runner::panic_message(payload.as_ref())))
+}
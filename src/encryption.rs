@@ -0,0 +1,259 @@
+//! Keeping puzzle inputs encrypted, so that they can be committed without
+//! publishing them.
+//!
+//! An encrypted input sits next to where its plaintext would be, with `.enc`
+//! added to the name. It holds a marker, the salt used to derive the key from
+//! the secret, the nonce, and the input encrypted with ChaCha20-Poly1305.
+
+use std::path::{Path, PathBuf};
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::{error::Error, runner::DaySelection};
+
+/// Identifies a file as one of ours, and the version of the format.
+const MAGIC: &[u8] = b"aoc-enc1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// What inputs are encrypted with: a passphrase, or the contents of a key file.
+pub struct Secret(Vec<u8>);
+
+impl Secret {
+    /// Uses `passphrase` as the secret.
+    pub fn passphrase(passphrase: &str) -> Self {
+        Secret(passphrase.as_bytes().to_vec())
+    }
+
+    /// Uses the exact contents of the file at `path` as the secret, including
+    /// any trailing newline.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        std::fs::read(path)
+            .map(Secret)
+            .map_err(|e| Error::Input(path.display().to_string(), e))
+    }
+
+    /// The key file at `key_file` if one is given, or else the passphrase in
+    /// `AOC_PASSPHRASE`, or nothing if there's neither.
+    pub fn find(key_file: Option<&Path>) -> Result<Option<Self>, Error> {
+        match key_file {
+            Some(path) => Secret::load(path).map(Some),
+            None => Ok(std::env::var("AOC_PASSPHRASE")
+                .ok()
+                .map(|passphrase| Secret::passphrase(&passphrase))),
+        }
+    }
+
+    fn key(&self, salt: &[u8]) -> [u8; 32] {
+        let mut key = [0; 32];
+        Argon2::default()
+            .hash_password_into(&self.0, salt, &mut key)
+            .expect("the salt and key lengths to suit Argon2");
+        key
+    }
+}
+
+/// Where the encrypted copy of the file at `path` is kept.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".enc");
+    PathBuf::from(name)
+}
+
+/// Encrypts `plaintext` with a fresh salt and nonce.
+pub fn encrypt(secret: &Secret, plaintext: &[u8]) -> Vec<u8> {
+    let mut salt = [0; SALT_LEN];
+    let mut nonce = [0; NONCE_LEN];
+    getrandom::getrandom(&mut salt)
+        .and_then(|_| getrandom::getrandom(&mut nonce))
+        .expect("the system to provide random numbers");
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&secret.key(&salt)));
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .expect("the input to be small enough to encrypt");
+    [MAGIC, &salt, &nonce, &ciphertext].concat()
+}
+
+/// Decrypts something made by [`encrypt`], or nothing if it wasn't encrypted
+/// with `secret` or has been tampered with.
+pub fn decrypt(secret: &Secret, encrypted: &[u8]) -> Option<Vec<u8>> {
+    let rest = encrypted.strip_prefix(MAGIC)?;
+    if rest.len() < SALT_LEN + NONCE_LEN {
+        return None;
+    }
+    let (salt, rest) = rest.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&secret.key(salt)));
+    cipher.decrypt(Nonce::from_slice(nonce), ciphertext).ok()
+}
+
+/// Reads and decrypts the text in the encrypted file at `path`.
+pub fn read(secret: Option<&Secret>, path: &Path) -> Result<String, Error> {
+    let name = path.display().to_string();
+    let secret = secret.ok_or_else(|| Error::MissingSecret(Some(name.clone())))?;
+    let encrypted = std::fs::read(path).map_err(|e| Error::Input(name.clone(), e))?;
+    decrypt(secret, &encrypted)
+        .and_then(|plaintext| String::from_utf8(plaintext).ok())
+        .ok_or(Error::Decrypt(name))
+}
+
+/// Writes an encrypted copy of each selected day's input in `input_dir`, or of
+/// every input there if no days are selected, with the secret found from
+/// `key_file`.
+pub fn encrypt_inputs(
+    input_dir: &Path,
+    days: Option<&DaySelection>,
+    key_file: Option<&Path>,
+) -> Result<(), Error> {
+    let secret = Secret::find(key_file)?.ok_or(Error::MissingSecret(None))?;
+    for path in input_paths(input_dir, days, |path| path.exists()) {
+        let plaintext =
+            std::fs::read(&path).map_err(|e| Error::Input(path.display().to_string(), e))?;
+        let encrypted_path = encrypted_path(&path);
+        // Encrypting again would change every byte, so leave alone copies that
+        // are already up to date
+        let unchanged = std::fs::read(&encrypted_path)
+            .ok()
+            .and_then(|encrypted| decrypt(&secret, &encrypted))
+            .is_some_and(|decrypted| decrypted == plaintext);
+        if unchanged {
+            println!("{} is up to date", encrypted_path.display());
+            continue;
+        }
+        std::fs::write(&encrypted_path, encrypt(&secret, &plaintext))
+            .map_err(|e| Error::Write(encrypted_path.display().to_string(), e))?;
+        println!(
+            "Encrypted {} to {}",
+            path.display(),
+            encrypted_path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Writes the plaintext of each selected day's encrypted input in
+/// `input_dir`, or of every encrypted input there if no days are selected,
+/// leaving alone any plaintext that's already there. The secret is found from
+/// `key_file` once there's something to decrypt.
+pub fn decrypt_inputs(
+    input_dir: &Path,
+    days: Option<&DaySelection>,
+    key_file: Option<&Path>,
+) -> Result<(), Error> {
+    let mut secret = None;
+    for path in input_paths(input_dir, days, |path| encrypted_path(path).exists()) {
+        if path.exists() {
+            println!("Skipped {}, which already exists", path.display());
+            continue;
+        }
+        let encrypted_path = encrypted_path(&path);
+        if secret.is_none() {
+            secret = Secret::find(key_file)?;
+        }
+        let plaintext = read(secret.as_ref(), &encrypted_path)?;
+        std::fs::write(&path, plaintext)
+            .map_err(|e| Error::Write(path.display().to_string(), e))?;
+        println!(
+            "Decrypted {} to {}",
+            encrypted_path.display(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// The plaintext paths of the selected days' inputs, or of every day for
+/// which `exists` holds if none were selected.
+fn input_paths(
+    input_dir: &Path,
+    days: Option<&DaySelection>,
+    exists: impl Fn(&Path) -> bool,
+) -> Vec<PathBuf> {
+    let path = |day: u8| input_dir.join(format!("day{day}.txt"));
+    match days {
+        Some(days) => days.days().iter().map(|&day| path(day)).collect(),
+        None => (1..=25).map(path).filter(|path| exists(path)).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8] = b"467..114..\n...*......\n";
+
+    fn secret() -> Secret {
+        Secret::passphrase("correct horse battery staple")
+    }
+
+    #[test]
+    fn decrypts_what_was_encrypted() {
+        let secret = secret();
+        assert_eq!(
+            decrypt(&secret, &encrypt(&secret, INPUT)).as_deref(),
+            Some(INPUT)
+        );
+        assert_eq!(decrypt(&secret, &encrypt(&secret, b"")), Some(Vec::new()));
+    }
+
+    #[test]
+    fn encrypts_differently_every_time() {
+        let secret = secret();
+        let encrypted = encrypt(&secret, INPUT);
+        assert!(encrypted.starts_with(MAGIC));
+        assert_ne!(encrypted, encrypt(&secret, INPUT));
+    }
+
+    #[test]
+    fn rejects_the_wrong_secret() {
+        let encrypted = encrypt(&secret(), INPUT);
+        assert_eq!(decrypt(&Secret::passphrase("hunter2"), &encrypted), None);
+    }
+
+    #[test]
+    fn rejects_truncated_files() {
+        let secret = secret();
+        let encrypted = encrypt(&secret, INPUT);
+        for len in [
+            encrypted.len() - 1,
+            MAGIC.len() + SALT_LEN + NONCE_LEN,
+            MAGIC.len() + SALT_LEN,
+            MAGIC.len(),
+            0,
+        ] {
+            assert_eq!(decrypt(&secret, &encrypted[..len]), None, "{len} bytes");
+        }
+    }
+
+    #[test]
+    fn rejects_tampered_files() {
+        let secret = secret();
+        let mut encrypted = encrypt(&secret, INPUT);
+        *encrypted.last_mut().unwrap() ^= 1;
+        assert_eq!(decrypt(&secret, &encrypted), None);
+    }
+
+    #[test]
+    fn rejects_files_without_the_marker() {
+        let secret = secret();
+        let encrypted = encrypt(&secret, INPUT);
+        assert_eq!(decrypt(&secret, &encrypted[MAGIC.len()..]), None);
+        assert_eq!(decrypt(&secret, INPUT), None);
+
+        let mut other_version = encrypted.clone();
+        other_version[MAGIC.len() - 1] = b'2';
+        assert_eq!(decrypt(&secret, &other_version), None);
+    }
+
+    #[test]
+    fn keeps_encrypted_copies_next_to_the_plaintext() {
+        assert_eq!(
+            encrypted_path(Path::new("input/2023/day1.txt")),
+            Path::new("input/2023/day1.txt.enc")
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Errors, and diagnostics for bad puzzle input.
+
+use std::{fmt, io, time::Duration};
+
+/// Everything that can go wrong while running, fetching or submitting.
+#[derive(Debug)]
+pub enum Error {
+    /// No solver is registered for the day
+    NotImplemented(u8),
+    /// No days have been implemented for the year
+    UnknownYear(u16),
+    /// The solver panicked with this message
+    Panicked(String),
+    /// The solver was still running when its timeout ran out
+    TimedOut(Duration),
+    /// The input couldn't be parsed
+    Parse(ParseError),
+    /// The file at the path couldn't be read
+    Input(String, io::Error),
+    /// The file at the path couldn't be written
+    Write(String, io::Error),
+    /// A request to the URL failed for the given reason
+    Http(String, String),
+    /// No session token was found, optionally naming the file that was tried
+    MissingSession(Option<String>),
+    /// The day's puzzle hasn't been released yet
+    Locked(u8),
+    /// Submitting would be pointless, for the given reason
+    Resubmission(String),
+    /// The answers file at the path is invalid
+    Answers(String, String),
+    /// The examples file at the path is invalid
+    Examples(String, String),
+    /// The day has no examples for the part that was run
+    NoExamples(u8),
+    /// A new day couldn't be created, for the given reason
+    Scaffold(String),
+    /// Answers can't be checked or recorded for input read from stdin
+    StdinAnswers,
+    /// This many parts didn't match their recorded answers
+    CheckFailed(usize),
+    /// This many parts ran but didn't produce an answer
+    PartsFailed(usize),
+    /// This many assumptions didn't hold for the inputs
+    AssumptionsFailed(usize),
+    /// The day has no way of generating inputs
+    NoGenerator(u8),
+    /// The day has no reference solutions to compare against
+    NoReference(u8),
+    /// This many parts disagreed with their reference solutions
+    Disagreements(usize),
+    /// Cargo couldn't be run to rebuild, or the rebuilt binary couldn't be
+    /// started
+    Rebuild(io::Error),
+    /// No passphrase or key file was given, optionally naming the encrypted
+    /// file that needed one
+    MissingSecret(Option<String>),
+    /// The encrypted file at the path couldn't be decrypted
+    Decrypt(String),
+    /// This many parts got slower than their baselines allow
+    Regressions(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotImplemented(day) => write!(f, "Day {day} has not yet been implemented"),
+            Error::UnknownYear(year) => write!(f, "No days of {year} have been implemented"),
+            Error::Panicked(message) => write!(f, "Solver panicked: {message}"),
+            Error::TimedOut(timeout) => write!(f, "Timed out after {timeout:.2?}"),
+            Error::Parse(e) => write!(f, "{e}"),
+            Error::Input(path, e) => write!(f, "Could not read input '{path}': {e}"),
+            Error::Write(path, e) => write!(f, "Could not write '{path}': {e}"),
+            Error::Http(url, e) => write!(f, "Request to {url} failed: {e}"),
+            Error::MissingSession(None) => write!(f, "No session token: set AOC_SESSION"),
+            Error::MissingSession(Some(path)) => {
+                write!(
+                    f,
+                    "No session token: set AOC_SESSION or write it to '{path}'"
+                )
+            }
+            Error::Locked(day) => write!(f, "Day {day} has not been unlocked yet"),
+            Error::Resubmission(reason) => write!(f, "Not submitting, {reason}"),
+            Error::Answers(path, e) => write!(f, "Invalid answers file '{path}': {e}"),
+            Error::Examples(path, e) => write!(f, "Invalid examples file '{path}': {e}"),
+            Error::NoExamples(day) => write!(f, "Day {day} has no examples for this part"),
+            Error::Scaffold(reason) => write!(f, "Could not create the day: {reason}"),
+            Error::StdinAnswers => write!(f, "Answers can't be checked or recorded for stdin"),
+            Error::CheckFailed(count) => write!(f, "{count} answer(s) did not match"),
+            Error::PartsFailed(count) => write!(f, "{count} part(s) failed"),
+            Error::AssumptionsFailed(count) => write!(f, "{count} assumption(s) did not hold"),
+            Error::NoGenerator(day) => write!(f, "Day {day} has no input generator"),
+            Error::NoReference(day) => write!(f, "Day {day} has no reference solutions"),
+            Error::Disagreements(count) => {
+                write!(f, "{count} part(s) disagreed with the reference solutions")
+            }
+            Error::Rebuild(e) => write!(f, "Could not rebuild and restart: {e}"),
+            Error::MissingSecret(None) => {
+                write!(f, "No passphrase or key file: set AOC_PASSPHRASE or use --key-file")
+            }
+            Error::MissingSecret(Some(path)) => write!(
+                f,
+                "No passphrase or key file to decrypt '{path}' with: set AOC_PASSPHRASE or use --key-file"
+            ),
+            Error::Regressions(count) => {
+                write!(f, "{count} part(s) got slower than their baselines")
+            }
+            Error::Decrypt(path) => write!(
+                f,
+                "Could not decrypt '{path}': the passphrase or key file is wrong, or the file is damaged"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// A problem with a specific piece of puzzle input. Days only know the line
+/// they were parsing, so the runner fills in the day and file afterwards.
+#[derive(Debug)]
+pub struct ParseError {
+    /// The day whose input this is, once known
+    pub day: Option<u8>,
+    /// Where the input was read from, once known
+    pub file: Option<String>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column of the start of `text`
+    pub column: usize,
+    /// The offending text, which is empty if something was missing
+    pub text: String,
+    /// What was wrong with `text`
+    pub message: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// An error about `text`, found at `column` of `source_line`. Days usually
+    /// go through [`Line::error`](crate::parse::Line::error) instead.
+    pub fn new(
+        line: usize,
+        column: usize,
+        source_line: &str,
+        text: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            day: None,
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+            source_line: source_line.to_string(),
+        }
+    }
+
+    /// Records which day and file the error came from.
+    pub fn in_file(mut self, day: u8, file: &str) -> Self {
+        self.day = Some(day);
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {day}: ")?;
+        }
+        writeln!(f, "{}", self.message)?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        let file = self.file.as_deref().unwrap_or("<input>");
+        writeln!(f, "{gutter}--> {file}:{}:{}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(usize::max(self.text.len(), 1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
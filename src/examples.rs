@@ -0,0 +1,149 @@
+//! Example inputs from the puzzle descriptions, with their expected answers.
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::{
+    answer::Value,
+    answers::Check,
+    error::Error,
+    input::RawInput,
+    runner::{self, DaySelection, Outcome, PartSelection},
+    solver::Registry,
+    Part,
+};
+
+/// A named example input, along with the answer for each part it's an example
+/// of.
+#[derive(Deserialize)]
+pub struct Example {
+    /// Identifies the example among the day's others
+    pub name: String,
+    /// The example input itself
+    pub input: String,
+    /// The expected answer to part one, if this is an example of it
+    pub part_one: Option<String>,
+    /// The expected answer to part two, if this is an example of it
+    pub part_two: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ExamplesFile {
+    #[serde(default, rename = "example")]
+    examples: Vec<Example>,
+}
+
+impl Example {
+    /// The expected answer to `part`, if this is an example of it.
+    pub fn expected(&self, part: &Part) -> Option<Value> {
+        let expected = match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        };
+        expected
+            .as_deref()
+            .map(|answer| str::parse::<Value>(answer).unwrap())
+    }
+
+    /// The lines of the input.
+    pub fn lines(&self) -> Vec<String> {
+        self.input.lines().map(str::to_owned).collect()
+    }
+}
+
+/// Where `day`'s examples are kept, which is `examples/day{N}.toml` inside the
+/// input directory.
+pub fn path(input_dir: &Path, day: u8) -> PathBuf {
+    input_dir.join("examples").join(format!("day{day}.toml"))
+}
+
+/// Loads `day`'s examples, or none if it doesn't have any yet.
+pub fn load(input_dir: &Path, day: u8) -> Result<Vec<Example>, Error> {
+    let path = path(input_dir, day);
+    match std::fs::read_to_string(&path) {
+        Ok(text) => toml::from_str::<ExamplesFile>(&text)
+            .map(|file| file.examples)
+            .map_err(|e| Error::Examples(path.display().to_string(), e.to_string())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(Error::Input(path.display().to_string(), e)),
+    }
+}
+
+/// Runs every example of each selected part on up to `jobs` threads,
+/// comparing each answer with the expected one. Parts without any examples get
+/// a single row saying so.
+pub fn run_examples(
+    registry: &Registry,
+    days: &DaySelection,
+    parts: &PartSelection,
+    input_dir: &Path,
+    timeout: Option<Duration>,
+    jobs: usize,
+) -> Result<Vec<(Outcome, String, Option<Check>)>, Error> {
+    let examples = days
+        .days()
+        .iter()
+        .map(|&day| Ok((day, load(input_dir, day)?)))
+        .collect::<Result<Vec<(u8, Vec<Example>)>, Error>>()?;
+
+    let mut tasks = Vec::new();
+    for (day, examples) in &examples {
+        for part in parts.parts() {
+            let before = tasks.len();
+            for example in examples {
+                if let Some(expected) = example.expected(&part) {
+                    tasks.push((*day, part.clone(), Some((example, expected))));
+                }
+            }
+            if tasks.len() == before {
+                tasks.push((*day, part, None));
+            }
+        }
+    }
+
+    Ok(runner::run_parallel(tasks, jobs, |(day, part, example)| {
+        let Some((example, expected)) = example else {
+            let error = match registry.get(day) {
+                Ok(_) => Error::NoExamples(day),
+                Err(e) => e,
+            };
+            let outcome = Outcome {
+                day,
+                part,
+                answer: Err(error),
+                elapsed: Default::default(),
+                cached: false,
+                allocations: None,
+            };
+            return (outcome, String::new(), None);
+        };
+
+        let name = format!("{} ({})", path(input_dir, day).display(), example.name);
+        let input = RawInput::from_lines(example.lines(), name);
+        let (answer, elapsed, allocations) =
+            match runner::run_input(registry, day, &part, input, timeout) {
+                Ok(solved) => (Ok(solved.answer), solved.elapsed, solved.allocations),
+                Err(e) => (Err(e), Default::default(), None),
+            };
+        let check = answer.as_ref().ok().map(|answer| {
+            if *answer.value() == expected {
+                Check::Pass
+            } else {
+                Check::Fail(expected)
+            }
+        });
+        let outcome = Outcome {
+            day,
+            part,
+            answer,
+            elapsed,
+            cached: false,
+            allocations,
+        };
+        (outcome, example.name.clone(), check)
+    }))
+}
@@ -0,0 +1,42 @@
+//! Downloading puzzle inputs.
+
+use std::path::Path;
+
+use crate::{
+    client::{self, Client},
+    encryption,
+    error::Error,
+    runner::DaySelection,
+};
+
+/// Downloads each day's input into `input_dir`, skipping days that have
+/// already been downloaded, or whose encrypted copy has been committed, so the
+/// website is only ever asked once per day. An empty input, as left by
+/// [`scaffold`](crate::scaffold::scaffold), doesn't count.
+pub fn fetch(
+    client: &Client,
+    year: u16,
+    days: &DaySelection,
+    input_dir: &Path,
+) -> Result<(), Error> {
+    for &day in days.days() {
+        let path = input_dir.join(format!("day{day}.txt"));
+        let cached = [encryption::encrypted_path(&path), path.clone()]
+            .into_iter()
+            .rfind(|path| path.metadata().is_ok_and(|metadata| metadata.len() > 0));
+        if let Some(cached) = cached {
+            println!("Day {day}: already cached at {}", cached.display());
+            continue;
+        }
+        if !client::is_unlocked(year, day) {
+            return Err(Error::Locked(day));
+        }
+
+        let input = client.input(year, day)?;
+        std::fs::create_dir_all(input_dir)
+            .and_then(|_| std::fs::write(&path, input))
+            .map_err(|e| Error::Write(path.display().to_string(), e))?;
+        println!("Day {day}: saved to {}", path.display());
+    }
+    Ok(())
+}
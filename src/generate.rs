@@ -0,0 +1,18 @@
+//! Making up inputs, to reproduce failures from a seed and to stress test the
+//! solutions on inputs much larger than the real ones.
+
+use crate::{error::Error, rng::Rng, solver::Registry};
+
+/// Generates an input for `day` of roughly `size`, which is always the same
+/// for the same `seed`.
+pub fn generate(
+    registry: &Registry,
+    day: u8,
+    seed: u64,
+    size: usize,
+) -> Result<Vec<String>, Error> {
+    registry
+        .get(day)?
+        .generate(&mut Rng::new(seed), size)
+        .ok_or(Error::NoGenerator(day))
+}
@@ -0,0 +1,480 @@
+//! Where puzzle inputs are read from, and the forms days can read them in.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    mem,
+    path::{Path, PathBuf},
+    str,
+    sync::Arc,
+};
+
+use crate::{
+    encryption::{self, Secret},
+    error::{Error, ParseError},
+    parse::Line,
+};
+
+/// Where a day's puzzle input is read from.
+pub enum InputSource {
+    /// `day{N}.txt` inside a directory of inputs, or its encrypted copy
+    /// `day{N}.txt.enc` if the plaintext isn't there
+    Directory {
+        /// The directory of inputs
+        root: PathBuf,
+        /// The key file to decrypt encrypted inputs with, instead of the
+        /// passphrase in `AOC_PASSPHRASE`. Either is only looked for once an
+        /// input needs decrypting
+        key_file: Option<PathBuf>,
+    },
+    /// A single file, used regardless of the day
+    File(PathBuf),
+    /// Input piped in on stdin, read up front so every part can share it
+    Stdin(Arc<Vec<u8>>),
+}
+
+impl InputSource {
+    /// Chooses a source from the command line, where an `input` of `-` means
+    /// stdin.
+    pub fn new(
+        input: Option<PathBuf>,
+        root: PathBuf,
+        key_file: Option<PathBuf>,
+    ) -> Result<Self, Error> {
+        match input {
+            Some(path) if path.as_os_str() == "-" => {
+                let mut bytes = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|e| Error::Input("<stdin>".to_string(), e))?;
+                Ok(InputSource::Stdin(Arc::new(bytes)))
+            }
+            Some(path) => Ok(InputSource::File(path)),
+            None => Ok(InputSource::Directory { root, key_file }),
+        }
+    }
+
+    /// A description of where `day`'s input comes from, for messages.
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            InputSource::Directory { .. } if self.is_encrypted(day) => {
+                encryption::encrypted_path(&self.path(day).unwrap())
+                    .display()
+                    .to_string()
+            }
+            InputSource::Directory { .. } | InputSource::File(_) => {
+                self.path(day).unwrap().display().to_string()
+            }
+            InputSource::Stdin(_) => "<stdin>".to_string(),
+        }
+    }
+
+    /// How `day`'s input is identified in the answers file, which is the file
+    /// name for inputs in the input directory, whether or not it's encrypted.
+    /// Stdin has no stable identity.
+    pub fn key(&self, day: u8) -> Option<String> {
+        match self {
+            InputSource::Directory { .. } => self.path(day).and_then(|path| {
+                path.file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            }),
+            InputSource::File(path) => Some(path.display().to_string()),
+            InputSource::Stdin(_) => None,
+        }
+    }
+
+    /// Reads the lines of `day`'s input.
+    pub fn read(&self, day: u8) -> Result<Vec<String>, Error> {
+        let mut input = self.open(day)?;
+        let lines = input.take_lines();
+        input.finish().map(|()| lines)
+    }
+
+    /// Opens `day`'s input without reading it yet, unless it has to be
+    /// decrypted first.
+    pub fn open(&self, day: u8) -> Result<RawInput, Error> {
+        match self {
+            InputSource::Stdin(bytes) => Ok(RawInput {
+                name: "<stdin>".to_string(),
+                contents: Contents::Bytes(Arc::clone(bytes)),
+                error: None,
+            }),
+            InputSource::Directory { key_file, .. } if self.is_encrypted(day) => {
+                let path = encryption::encrypted_path(&self.path(day).unwrap());
+                let secret = Secret::find(key_file.as_deref())?;
+                let text = encryption::read(secret.as_ref(), &path)?;
+                Ok(RawInput::from_text(text, path.display().to_string()))
+            }
+            _ => RawInput::open(&self.path(day).unwrap()),
+        }
+    }
+
+    /// Whether `day`'s input will be read from its encrypted copy, which is
+    /// only used when there's no plaintext.
+    fn is_encrypted(&self, day: u8) -> bool {
+        match self {
+            InputSource::Directory { .. } => {
+                let path = self.path(day).unwrap();
+                !path.exists() && encryption::encrypted_path(&path).exists()
+            }
+            _ => false,
+        }
+    }
+
+    fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Directory { root, .. } => Some(root.join(format!("day{day}.txt"))),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin(_) => None,
+        }
+    }
+}
+
+/// A day's puzzle input before it's parsed. Days choose which form to read it
+/// in: owned lines, borrowed lines, raw bytes, or a line at a time. Only the
+/// last never holds the whole input in memory, so it suits huge generated
+/// inputs. Each form reads the input, so a day should stick to one of them.
+///
+/// Reading never fails as far as the day is concerned. If the file can't be
+/// read the input just ends early, and the runner reports the error instead of
+/// whatever the day made of it.
+pub struct RawInput {
+    name: String,
+    contents: Contents,
+    error: Option<io::Error>,
+}
+
+enum Contents {
+    Lines(Vec<String>),
+    /// Shared so that every part run on stdin reads the same copy
+    Bytes(Arc<Vec<u8>>),
+    Reader(BufReader<File>),
+}
+
+impl RawInput {
+    /// Input that has already been split into lines, named `name` in any
+    /// errors.
+    pub fn from_lines(lines: Vec<String>, name: impl Into<String>) -> Self {
+        RawInput {
+            name: name.into(),
+            contents: Contents::Lines(lines),
+            error: None,
+        }
+    }
+
+    /// Input that has already been read, named `name` in any errors.
+    pub fn from_text(text: String, name: impl Into<String>) -> Self {
+        RawInput {
+            name: name.into(),
+            contents: Contents::Bytes(Arc::new(text.into_bytes())),
+            error: None,
+        }
+    }
+
+    /// Opens the file at `path`, to be read as the day asks for it.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let name = path.display().to_string();
+        let file = File::open(path).map_err(|e| Error::Input(name.clone(), e))?;
+        Ok(RawInput {
+            name,
+            contents: Contents::Reader(BufReader::new(file)),
+            error: None,
+        })
+    }
+
+    /// Where the input came from, for messages.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Takes every line, as [`Solver::parse`](crate::Solver::parse) expects.
+    pub fn take_lines(&mut self) -> Vec<String> {
+        match mem::replace(&mut self.contents, Contents::Lines(Vec::new())) {
+            Contents::Lines(lines) => lines,
+            Contents::Bytes(bytes) => match str::from_utf8(&bytes) {
+                Ok(text) => to_lines(text),
+                Err(e) => {
+                    self.error = Some(io::Error::new(io::ErrorKind::InvalidData, e));
+                    Vec::new()
+                }
+            },
+            Contents::Reader(mut reader) => {
+                let mut lines = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    match reader.read_line(&mut line) {
+                        Ok(0) => break,
+                        Ok(_) => {
+                            line.truncate(trim_newline(&line).len());
+                            lines.push(line);
+                        }
+                        Err(e) => {
+                            self.error = Some(e);
+                            break;
+                        }
+                    }
+                }
+                lines
+            }
+        }
+    }
+
+    /// Every line, borrowed from the input instead of copied out of it. If the
+    /// input isn't UTF-8 it ends just before the first invalid byte.
+    pub fn lines(&mut self) -> impl Iterator<Item = Line<'_>> {
+        self.read_bytes();
+        let Contents::Bytes(bytes) = &self.contents else {
+            unreachable!("the input to have just been read into bytes")
+        };
+        let text = str::from_utf8(bytes).unwrap_or_else(|e| {
+            self.error = Some(io::Error::new(io::ErrorKind::InvalidData, e));
+            str::from_utf8(&bytes[..e.valid_up_to()]).unwrap()
+        });
+        text.lines()
+            .enumerate()
+            .map(|(index, text)| Line::new(index, text))
+    }
+
+    /// The whole input as it was read, without being split into lines or
+    /// checked to be UTF-8.
+    pub fn bytes(&mut self) -> &[u8] {
+        self.read_bytes()
+    }
+
+    /// Reads the input a line at a time, reusing the same buffer for each
+    /// line where it can.
+    pub fn stream(&mut self) -> LineStream<'_> {
+        LineStream {
+            input: self,
+            index: 0,
+            offset: 0,
+            buffer: String::new(),
+        }
+    }
+
+    /// Parses each line with `parse` as it's read, so only what the lines
+    /// parse into is kept, stopping at the first error.
+    pub fn parse_lines<T>(
+        &mut self,
+        mut parse: impl FnMut(Line<'_>) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut lines = self.stream();
+        let mut parsed = Vec::new();
+        while let Some(line) = lines.next_line() {
+            parsed.push(parse(line)?);
+        }
+        Ok(parsed)
+    }
+
+    /// Fails if anything went wrong while the input was being read.
+    pub fn finish(&mut self) -> Result<(), Error> {
+        match self.error.take() {
+            Some(e) => Err(Error::Input(self.name.clone(), e)),
+            None => Ok(()),
+        }
+    }
+
+    fn read_bytes(&mut self) -> &[u8] {
+        match &mut self.contents {
+            Contents::Lines(lines) => {
+                let bytes = lines.iter().flat_map(|line| [line.as_bytes(), b"\n"]);
+                self.contents = Contents::Bytes(Arc::new(bytes.flatten().copied().collect()));
+            }
+            Contents::Reader(reader) => {
+                let mut bytes = Vec::new();
+                if let Err(e) = reader.read_to_end(&mut bytes) {
+                    self.error = Some(e);
+                }
+                self.contents = Contents::Bytes(Arc::new(bytes));
+            }
+            Contents::Bytes(_) => {}
+        }
+        match &self.contents {
+            Contents::Bytes(bytes) => bytes.as_slice(),
+            _ => unreachable!("the input to have just been read into bytes"),
+        }
+    }
+}
+
+/// The lines of a [`RawInput`], read one at a time by
+/// [`RawInput::stream`].
+pub struct LineStream<'a> {
+    input: &'a mut RawInput,
+    index: usize,
+    offset: usize,
+    buffer: String,
+}
+
+impl LineStream<'_> {
+    /// The next line, or nothing once the input has ended. The line is only
+    /// borrowed until the next one is read.
+    pub fn next_line(&mut self) -> Option<Line<'_>> {
+        let text = match &mut self.input.contents {
+            Contents::Lines(lines) => lines.get(self.index)?.as_str(),
+            Contents::Bytes(bytes) => {
+                let rest = &bytes[self.offset..];
+                if rest.is_empty() {
+                    return None;
+                }
+                let end = rest
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .map_or(rest.len(), |end| end + 1);
+                self.offset += end;
+                match str::from_utf8(&rest[..end]) {
+                    Ok(line) => trim_newline(line),
+                    Err(e) => {
+                        self.input.error = Some(io::Error::new(io::ErrorKind::InvalidData, e));
+                        return None;
+                    }
+                }
+            }
+            Contents::Reader(reader) => {
+                self.buffer.clear();
+                match reader.read_line(&mut self.buffer) {
+                    Ok(0) => return None,
+                    Ok(_) => trim_newline(&self.buffer),
+                    Err(e) => {
+                        self.input.error = Some(e);
+                        return None;
+                    }
+                }
+            }
+        };
+        self.index += 1;
+        Some(Line::new(self.index - 1, text))
+    }
+}
+
+/// Removes the line ending from `line`, in the same way [`str::lines`] does.
+fn trim_newline(line: &str) -> &str {
+    match line.strip_suffix('\n') {
+        Some(line) => line.strip_suffix('\r').unwrap_or(line),
+        None => line,
+    }
+}
+
+fn to_lines(text: &str) -> Vec<String> {
+    text.lines().map(str::to_owned).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs, process,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+
+    /// Calls `check` with a way to open `text` as each kind of input: already
+    /// split into lines (if it's UTF-8), already read, and read from a file.
+    fn with_backings(text: &[u8], check: impl Fn(&dyn Fn() -> RawInput)) {
+        static FILES: AtomicUsize = AtomicUsize::new(0);
+
+        if let Ok(text) = str::from_utf8(text) {
+            check(&|| RawInput::from_lines(to_lines(text), "lines"));
+        }
+        check(&|| RawInput {
+            name: "bytes".to_string(),
+            contents: Contents::Bytes(Arc::new(text.to_vec())),
+            error: None,
+        });
+        let file = FILES.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("aoc-input-test-{}-{file}.txt", process::id()));
+        fs::write(&path, text).unwrap();
+        check(&|| RawInput::open(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+    }
+
+    fn numbered(line: Line<'_>) -> (usize, String) {
+        (line.error("", "").line, line.text().to_string())
+    }
+
+    fn streamed(input: &mut RawInput) -> Vec<(usize, String)> {
+        let mut lines = input.stream();
+        let mut read = Vec::new();
+        while let Some(line) = lines.next_line() {
+            read.push(numbered(line));
+        }
+        read
+    }
+
+    /// Checks that every form of every backing of `text` reads `expected`,
+    /// with the same line numbers.
+    fn assert_lines(text: &[u8], expected: &[&str]) {
+        let expected = (1..)
+            .zip(expected.iter().map(|line| line.to_string()))
+            .collect::<Vec<_>>();
+        with_backings(text, |open| {
+            let mut input = open();
+            assert_eq!(streamed(&mut input), expected, "{}", input.name());
+            assert!(input.finish().is_ok());
+
+            let mut input = open();
+            let borrowed = input.lines().map(numbered).collect::<Vec<_>>();
+            assert_eq!(borrowed, expected, "{}", input.name());
+            assert!(input.finish().is_ok());
+
+            let mut input = open();
+            let owned = (1..).zip(input.take_lines()).collect::<Vec<_>>();
+            assert_eq!(owned, expected, "{}", input.name());
+            assert!(input.finish().is_ok());
+        });
+    }
+
+    #[test]
+    fn reads_plain_lines() {
+        assert_lines(b"a\nb c\n", &["a", "b c"]);
+    }
+
+    #[test]
+    fn strips_crlf_endings() {
+        assert_lines(b"a\r\nb\r\n", &["a", "b"]);
+    }
+
+    #[test]
+    fn reads_a_missing_final_newline() {
+        assert_lines(b"a\nb", &["a", "b"]);
+        assert_lines(b"a\r\nb", &["a", "b"]);
+    }
+
+    #[test]
+    fn keeps_blank_lines() {
+        assert_lines(b"a\n\n", &["a", ""]);
+        assert_lines(b"\na\n\nb\n", &["", "a", "", "b"]);
+    }
+
+    #[test]
+    fn reads_nothing_from_an_empty_input() {
+        assert_lines(b"", &[]);
+    }
+
+    #[test]
+    fn reads_bytes_as_they_are() {
+        let text = b"a\r\n\xffb";
+        with_backings(text, |open| {
+            let mut input = open();
+            assert_eq!(input.bytes(), text);
+            assert!(input.finish().is_ok());
+        });
+    }
+
+    #[test]
+    fn reports_invalid_utf8_when_finished() {
+        let is_invalid_data = |result: Result<(), Error>| matches!(result, Err(Error::Input(_, e)) if e.kind() == io::ErrorKind::InvalidData);
+        with_backings(b"a\n\xff\nc\n", |open| {
+            let mut input = open();
+            streamed(&mut input);
+            assert!(is_invalid_data(input.finish()), "{}", input.name());
+
+            let mut input = open();
+            input.lines().for_each(drop);
+            assert!(is_invalid_data(input.finish()), "{}", input.name());
+
+            let mut input = open();
+            input.take_lines();
+            assert!(is_invalid_data(input.finish()), "{}", input.name());
+        });
+    }
+}
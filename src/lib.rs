@@ -0,0 +1,112 @@
+//! Solutions to Advent of Code, along with everything needed to run, time and
+//! check them.
+//!
+//! Each year's days live in their own module, such as [`year2023`], and share
+//! the rest of the crate: parsing helpers, the runner, input handling and so
+//! on. Each day is a [`Solver`] whose `parse` turns the puzzle input into the
+//! day's own input type, which `part_one` and `part_two` then solve:
+//!
+//! ```no_run
+//! use aoc_2023::{year2023::day6::Day6, Solver};
+//!
+//! let lines = vec!["Time:      7  15   30".to_string(), "Distance:  9  40  200".to_string()];
+//! let races = Day6::parse(lines).unwrap();
+//! println!("{}", Day6::part_one(&races));
+//! ```
+//!
+//! Days that don't need the whole input in memory can override `parse_raw` to
+//! read it a line at a time instead.
+//!
+//! [`registry`] collects every day of a year so they can be run by number
+//! instead.
+
+#![warn(missing_docs)]
+
+pub mod allocations;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod cancel;
+pub mod client;
+pub mod differential;
+pub mod encryption;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod generate;
+pub mod input;
+pub mod parse;
+pub mod report;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+mod template;
+pub mod timings;
+mod tsv;
+pub mod validate;
+pub mod watch;
+
+pub mod year2023;
+
+use std::fmt;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+pub use crate::{
+    answer::{Answer, Value},
+    error::{Error, ParseError},
+    solver::{Registry, Solver},
+};
+
+/// The year used when none is given.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// One of the two parts of a day's puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Part {
+    /// The first part
+    One,
+    /// The second part, unlocked by solving the first
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+impl Part {
+    /// The part's number, which is what the website calls its level.
+    pub fn level(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    /// The part numbered `level`, if there is one.
+    pub fn from_level(level: u8) -> Option<Self> {
+        match level {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+/// Every implemented day of `year`.
+pub fn registry(year: u16) -> Result<Registry, Error> {
+    match year {
+        2023 => Ok(year2023::registry()),
+        _ => Err(Error::UnknownYear(year)),
+    }
+}
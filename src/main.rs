@@ -1,66 +1,659 @@
-mod template;
-
-mod day1;
-mod day10;
-mod day11;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-
-use clap::{Parser, ValueEnum};
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use clap::{
+    error::ErrorKind, parser::ValueSource, ArgMatches, Args as ClapArgs, CommandFactory,
+    FromArgMatches, Parser, Subcommand,
+};
+
+use aoc_2023::{
+    answers::{self, Answers, Check},
+    bench,
+    cache::{self, Cache},
+    client::{self, Client},
+    differential, encryption, examples, fetch, generate,
+    input::InputSource,
+    registry,
+    report::{self, Format},
+    runner::{self, DaySelection, Outcome, PartSelection},
+    scaffold,
+    submit::{self, History},
+    timings::Timings,
+    validate::{self, Validation},
+    watch::{self, Watch},
+    Error, Part, Registry, Value, DEFAULT_YEAR,
+};
 
 #[derive(Parser)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Which year's puzzles to work on
+    #[arg(short, long, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR, global = true)]
+    year: u16,
+
+    /// Directory containing a directory for each year, which holds each day's
+    /// `dayN.txt` input and its examples in `examples/dayN.toml`
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = "input", global = true)]
+    input_dir: PathBuf,
+
+    /// File whose contents encrypt and decrypt inputs, instead of the
+    /// passphrase in `AOC_PASSPHRASE`
+    #[arg(long, env = "AOC_KEY_FILE", global = true)]
+    key_file: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download puzzle inputs into the input directory
+    Fetch(FetchArgs),
+    /// Solve a part with the real input and submit the answer
+    Submit(SubmitArgs),
+    /// Start a new day from the template
+    New(NewArgs),
+    /// Check inputs against the assumptions each day's solutions make
+    Validate(ValidateArgs),
+    /// Make up an input for a day and print it
+    Generate(GenerateArgs),
+    /// Compare solutions with their slow reference solutions on generated
+    /// inputs
+    Differential(DifferentialArgs),
+    /// Re-run a day whenever its input, examples or answers change
+    Watch(WatchArgs),
+    /// Manage the cache of answers from earlier runs
+    #[command(subcommand)]
+    Cache(CacheCommand),
+    /// Write an encrypted `dayN.txt.enc` copy of each input, which is safe to
+    /// commit
+    Encrypt(EncryptionArgs),
+    /// Write the plaintext of each encrypted input that doesn't have one
+    Decrypt(EncryptionArgs),
+    /// Show how each part's benchmark times have changed over time
+    History(HistoryArgs),
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Forget every cached answer for the year
+    Clear,
+}
+
+#[derive(ClapArgs)]
+struct FetchArgs {
+    /// Days to download, e.g. `3` or `1-5,8`
+    #[arg(short, long)]
+    day: DaySelection,
+
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(ClapArgs)]
+struct SubmitArgs {
     #[arg(short, long)]
     day: u8,
 
     #[arg(short, long)]
     part: Part,
 
+    /// Submit this answer instead of solving the part
+    #[arg(short, long)]
+    answer: Option<String>,
+
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(ClapArgs)]
+struct ValidateArgs {
+    /// Days to check, e.g. `3` or `1-5,8`; checks every implemented day if
+    /// omitted
+    #[arg(short, long)]
+    day: Option<DaySelection>,
+
+    /// Check this file instead, or stdin if `-`
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+}
+
+#[derive(ClapArgs)]
+struct GenerateArgs {
+    #[arg(short, long)]
+    day: u8,
+
+    /// The same seed always generates the same input
+    #[arg(short, long)]
+    seed: u64,
+
+    /// Roughly how many lines to generate, or how wide for grids
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+    size: u64,
+}
+
+#[derive(ClapArgs)]
+struct DifferentialArgs {
+    /// Days to compare, e.g. `3` or `1-5,8`; compares every implemented day if
+    /// omitted
     #[arg(short, long)]
+    day: Option<DaySelection>,
+
+    #[arg(short, long, default_value = "both")]
+    part: PartSelection,
+
+    /// Try the inputs generated from this many seeds, counting up from zero
+    #[arg(long, default_value_t = 100)]
+    seeds: u64,
+
+    /// Roughly how big an input to generate; small inputs keep the reference
+    /// solutions quick and the disagreements readable
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u64).range(1..))]
+    size: u64,
+}
+
+#[derive(ClapArgs)]
+struct WatchArgs {
+    #[arg(short, long)]
+    day: u8,
+
+    #[arg(short, long, default_value = "both")]
+    part: PartSelection,
+
+    /// Run the day's examples instead of its real input
+    #[arg(short, long)]
+    test: bool,
+
+    /// Also watch the day's source, rebuilding and restarting when it changes
+    #[arg(long)]
+    build: bool,
+
+    /// The crate whose source is watched by `--build`
+    #[arg(long, default_value = env!("CARGO_MANIFEST_DIR"))]
+    root: PathBuf,
+
+    /// Give up on any part still running after this many seconds
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// How often to look for changes, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 250)]
+    interval: u64,
+
+    /// File of known-correct answers to compare with
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+#[derive(ClapArgs)]
+struct EncryptionArgs {
+    /// Days whose inputs to include, e.g. `3` or `1-5,8`; includes every day
+    /// with an input if omitted
+    #[arg(short, long)]
+    day: Option<DaySelection>,
+}
+
+#[derive(ClapArgs)]
+struct HistoryArgs {
+    /// Days to show, e.g. `3` or `1-5,8`; shows every implemented day if
+    /// omitted
+    #[arg(short, long)]
+    day: Option<DaySelection>,
+
+    #[arg(short, long, default_value = "both")]
+    part: PartSelection,
+}
+
+#[derive(ClapArgs)]
+struct NewArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The crate to add the day to
+    #[arg(long, default_value = env!("CARGO_MANIFEST_DIR"))]
+    root: PathBuf,
+}
+
+#[derive(ClapArgs)]
+struct RunArgs {
+    /// Days to run, e.g. `3` or `1-5,8`; runs every implemented day if omitted
+    #[arg(short, long)]
+    day: Option<DaySelection>,
+
+    #[arg(short, long, default_value = "both")]
+    part: PartSelection,
+
+    /// Run every example of each part instead, checking their answers
+    #[arg(short, long, conflicts_with_all = ["check", "record", "bench", "format"])]
     test: bool,
+
+    /// Read the input from this file instead, or from stdin if `-`
+    #[arg(short, long, requires = "day", conflicts_with = "test")]
+    input: Option<PathBuf>,
+
+    /// List the days that have been implemented
+    #[arg(short, long)]
+    list: bool,
+
+    /// Compare answers against the answers file, failing on any mismatch.
+    /// Every part is solved, even if its answer is cached
+    #[arg(long, conflicts_with = "record")]
+    check: bool,
+
+    /// Save answers into the answers file as the known-correct ones
+    #[arg(long)]
+    record: bool,
+
+    /// Time each part this many times after a warm-up run, reporting parse and
+    /// solve times separately
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..), conflicts_with_all = ["check", "record"])]
+    bench: Option<u32>,
+
+    /// Fail if a benchmarked part is more than this many percent slower than
+    /// its recent runs
+    #[arg(long, value_name = "PERCENT", value_parser = parse_percent, requires = "bench")]
+    max_slowdown: Option<f64>,
+
+    /// Give up on any part still running after this many seconds
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Run up to this many parts at once
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        default_value = "1",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with = "bench"
+    )]
+    jobs: u32,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value = "text", conflicts_with_all = ["check", "bench"])]
+    format: Format,
+
+    /// File of known-correct answers used by `--check` and `--record`
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Solve every part instead of using answers cached by earlier runs
+    #[arg(long, conflicts_with_all = ["test", "bench", "check"])]
+    no_cache: bool,
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    str::parse::<f64>(s)
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("invalid timeout '{s}'"))
 }
 
-#[derive(Clone, Debug, ValueEnum)]
-enum Part {
-    One,
-    Two,
+fn parse_percent(s: &str) -> Result<f64, String> {
+    str::parse::<f64>(s)
+        .ok()
+        .filter(|percent| percent.is_finite() && *percent >= 0.0)
+        .ok_or_else(|| format!("invalid percentage '{s}'"))
 }
 
 fn main() {
-    let args = Args::parse();
-    let filename = format!(
-        "input/day{}{}.txt",
-        args.day,
-        if args.test { "_test" } else { "" },
+    let matches = Args::command().get_matches();
+    if matches.subcommand().is_some() {
+        reject_run_flags(&matches);
+    }
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let Err(e) = run(args) {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+/// The flags for running days mean nothing to the other commands, so they're
+/// refused instead of being ignored. Clap can only do this itself by also
+/// refusing the global flags before a subcommand.
+fn reject_run_flags(matches: &ArgMatches) {
+    let run_args = RunArgs::augment_args(clap::Command::new("run"));
+    for arg in run_args.get_arguments() {
+        if matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine) {
+            Args::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    format!(
+                        "--{} can only be used when running days, not with a subcommand",
+                        arg.get_long().unwrap_or_default()
+                    ),
+                )
+                .exit();
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Error> {
+    let year = args.year;
+    let input_dir = args.input_dir.join(year.to_string());
+    let key_file = args.key_file;
+    match args.command {
+        Some(Command::Fetch(fetch_args)) => {
+            let client = Client::new(&fetch_args.base_url, client::load_session()?);
+            fetch::fetch(&client, year, &fetch_args.day, &input_dir)
+        }
+        Some(Command::Submit(submit_args)) => submit_answer(submit_args, year, input_dir, key_file),
+        Some(Command::New(new_args)) => {
+            scaffold::scaffold(&new_args.root, &input_dir, year, new_args.day)
+        }
+        Some(Command::Validate(validate_args)) => {
+            validate_inputs(validate_args, &registry(year)?, input_dir, key_file)
+        }
+        Some(Command::Generate(generate_args)) => generate_input(generate_args, &registry(year)?),
+        Some(Command::Differential(differential_args)) => {
+            compare_solutions(differential_args, &registry(year)?)
+        }
+        Some(Command::Watch(watch_args)) => watch_day(watch_args, year, input_dir, key_file),
+        Some(Command::Cache(CacheCommand::Clear)) => {
+            if cache::clear(&input_dir)? {
+                println!("Removed {}", Cache::path(&input_dir).display());
+            } else {
+                println!("Nothing is cached for {year}");
+            }
+            Ok(())
+        }
+        Some(Command::Encrypt(encryption_args)) => encryption::encrypt_inputs(
+            &input_dir,
+            encryption_args.day.as_ref(),
+            key_file.as_deref(),
+        ),
+        Some(Command::Decrypt(encryption_args)) => encryption::decrypt_inputs(
+            &input_dir,
+            encryption_args.day.as_ref(),
+            key_file.as_deref(),
+        ),
+        Some(Command::History(history_args)) => {
+            let days = match history_args.day {
+                Some(days) => days,
+                None => DaySelection::all(&registry(year)?),
+            };
+            report::print_timings(&Timings::load(&input_dir)?, &days, &history_args.part);
+            Ok(())
+        }
+        None => run_days(args.run, year, input_dir, key_file),
+    }
+}
+
+fn submit_answer(
+    args: SubmitArgs,
+    year: u16,
+    input_dir: PathBuf,
+    key_file: Option<PathBuf>,
+) -> Result<(), Error> {
+    let client = Client::new(&args.base_url, client::load_session()?);
+    let answer = match args.answer {
+        Some(answer) => str::parse::<Value>(&answer).unwrap(),
+        None => {
+            let source = InputSource::new(None, input_dir.clone(), key_file)?;
+            let answer =
+                runner::run_part(&registry(year)?, args.day, &args.part, &source, None)?.answer;
+            println!("{}: {}", answer.label(), answer.value());
+            answer.value().clone()
+        }
+    };
+    let mut history = History::load(&input_dir)?;
+    let verdict = submit::submit(&client, &mut history, year, args.day, args.part, answer)?;
+    println!("{verdict}");
+    Ok(())
+}
+
+fn generate_input(args: GenerateArgs, registry: &Registry) -> Result<(), Error> {
+    let lines = generate::generate(registry, args.day, args.seed, args.size as usize)?;
+    let mut stdout = io::stdout().lock();
+    lines
+        .iter()
+        .try_for_each(|line| writeln!(stdout, "{line}"))
+        .or_else(|e| match e.kind() {
+            // Piped into something like `head` that stopped reading
+            io::ErrorKind::BrokenPipe => Ok(()),
+            _ => Err(Error::Write("stdout".to_string(), e)),
+        })
+}
+
+fn compare_solutions(args: DifferentialArgs, registry: &Registry) -> Result<(), Error> {
+    let days = args.day.unwrap_or_else(|| DaySelection::all(registry));
+    let size = args.size as usize;
+    let comparisons =
+        differential::compare_selection(registry, &days, &args.part, args.seeds, size);
+    report::print_comparisons(&comparisons, size);
+
+    let disagreements = comparisons
+        .iter()
+        .filter(|comparison| matches!(comparison.result, Ok(Some(_))))
+        .count();
+    if disagreements > 0 {
+        Err(Error::Disagreements(disagreements))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_inputs(
+    args: ValidateArgs,
+    registry: &Registry,
+    input_dir: PathBuf,
+    key_file: Option<PathBuf>,
+) -> Result<(), Error> {
+    let days = args.day.unwrap_or_else(|| DaySelection::all(registry));
+    if args.input.is_some() && days.days().len() != 1 {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--input can only be used when validating a single day",
+            )
+            .exit();
+    }
+    let source = InputSource::new(args.input, input_dir, key_file)?;
+    let validations = validate::validate_selection(registry, &days, &source);
+    report::print_validations(&validations);
+
+    let failures = validations.iter().map(Validation::failures).sum();
+    if failures > 0 {
+        Err(Error::AssumptionsFailed(failures))
+    } else {
+        Ok(())
+    }
+}
+
+fn run_days(
+    args: RunArgs,
+    year: u16,
+    input_dir: PathBuf,
+    key_file: Option<PathBuf>,
+) -> Result<(), Error> {
+    let registry = registry(year)?;
+    if args.list {
+        for day in registry.days() {
+            println!("Day {day}");
+        }
+        return Ok(());
+    }
+
+    let days = args.day.unwrap_or_else(|| DaySelection::all(&registry));
+    if args.input.is_some() && days.days().len() != 1 {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--input can only be used when running a single day",
+            )
+            .exit();
+    }
+    if args.test {
+        let checks = examples::run_examples(
+            &registry,
+            &days,
+            &args.part,
+            &input_dir,
+            args.timeout,
+            args.jobs as usize,
+        )?;
+        return report_checks(&checks);
+    }
+
+    let cache = if args.no_cache || args.check || args.bench.is_some() {
+        None
+    } else {
+        Some(Cache::load(&input_dir)?)
+    };
+    let mut timings = Timings::load(&input_dir)?;
+    // Timings for other inputs wouldn't be comparable with the history
+    let keep_timings = args.input.is_none();
+    let source = InputSource::new(args.input, input_dir, key_file)?;
+    if let Some(samples) = args.bench {
+        let benchmarks =
+            bench::bench_selection(&registry, &days, &args.part, &source, samples, args.timeout);
+        report::print_bench(&benchmarks);
+        if !keep_timings {
+            return Ok(());
+        }
+        let regressions = args
+            .max_slowdown
+            .map(|percent| timings.regressions(&benchmarks, percent))
+            .unwrap_or_default();
+        timings.record(&benchmarks)?;
+        if regressions.is_empty() {
+            return Ok(());
+        }
+        println!();
+        report::print_regressions(&regressions);
+        return Err(Error::Regressions(regressions.len()));
+    }
+
+    let mut outcomes = runner::run_selection(
+        &registry,
+        &days,
+        &args.part,
+        &source,
+        args.timeout,
+        args.jobs as usize,
+        cache.as_ref(),
     );
-    let lines = get_input(&filename);
-    match args.day {
-        1 => day1::run(lines, args.part),
-        2 => day2::run(lines, args.part),
-        3 => day3::run(lines, args.part),
-        4 => day4::run(lines, args.part),
-        5 => day5::run(lines, args.part),
-        6 => day6::run(lines, args.part),
-        7 => day7::run(lines, args.part),
-        8 => day8::run(lines, args.part),
-        9 => day9::run(lines, args.part),
-        10 => day10::run(lines, args.part),
-        11 => day11::run(lines, args.part),
-        12..=25 => panic!("Day not yet implemented"),
-        _ => panic!("Entered a day that has not yet been implemented"),
-    }
-}
-
-fn get_input(filename: &str) -> Vec<String> {
-    std::fs::read_to_string(filename)
-        .unwrap()
-        .lines()
-        .map(str::to_owned)
-        .collect()
+    if args.check {
+        return check_answers(outcomes, year, &source, &args.answers);
+    } else if args.record {
+        record_answers(&outcomes, year, &source, &args.answers)?;
+    }
+
+    match args.format {
+        Format::Json => report::print_json(&outcomes, &source),
+        Format::Csv => report::print_csv(&outcomes, &source),
+        Format::Text if outcomes.len() == 1 => {
+            let outcome = outcomes.pop().unwrap();
+            // Not a failure, just as it isn't when running several parts
+            if let Err(e @ Error::NotImplemented(_)) = &outcome.answer {
+                println!("{e}");
+                return Ok(());
+            }
+            let answer = outcome.answer?;
+            println!("{}: {}", answer.label(), answer.value());
+            if outcome.cached {
+                println!("Elapsed: cached");
+            } else {
+                println!("Elapsed: {:.2?}", outcome.elapsed);
+            }
+            if let Some(allocations) = outcome.allocations {
+                println!(
+                    "Allocations: {} totalling {} bytes, peaking at {} bytes",
+                    allocations.count, allocations.bytes, allocations.peak
+                );
+            }
+        }
+        Format::Text => report::print_table(&outcomes),
+    }
+    // Only once the answers have been shown, so they aren't lost if it fails
+    if let Some(cache) = &cache {
+        cache.save()?;
+    }
+
+    let failures = outcomes
+        .iter()
+        .filter(|outcome| {
+            outcome
+                .answer
+                .as_ref()
+                .is_err_and(|e| !matches!(e, Error::NotImplemented(_)))
+        })
+        .count();
+    if failures > 0 {
+        Err(Error::PartsFailed(failures))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_answers(
+    outcomes: Vec<Outcome>,
+    year: u16,
+    source: &InputSource,
+    answers_path: &Path,
+) -> Result<(), Error> {
+    report_checks(&answers::compare(outcomes, year, source, answers_path)?)
+}
+
+/// Prints the checks, failing if any part had the wrong answer or none at all.
+fn report_checks(checks: &[(Outcome, String, Option<Check>)]) -> Result<(), Error> {
+    report::print_checks(checks);
+
+    let failures = checks
+        .iter()
+        .filter(|(outcome, _, check)| match check {
+            Some(check) => matches!(check, Check::Fail(_)),
+            None => !matches!(
+                outcome.answer,
+                Err(Error::NotImplemented(_) | Error::NoExamples(_))
+            ),
+        })
+        .count();
+    if failures > 0 {
+        Err(Error::CheckFailed(failures))
+    } else {
+        Ok(())
+    }
+}
+
+fn record_answers(
+    outcomes: &[Outcome],
+    year: u16,
+    source: &InputSource,
+    answers_path: &Path,
+) -> Result<(), Error> {
+    let mut answers = Answers::load(answers_path)?;
+    for outcome in outcomes {
+        if let Ok(answer) = &outcome.answer {
+            let input = source.key(outcome.day).ok_or(Error::StdinAnswers)?;
+            answers.record(year, outcome.day, &outcome.part, &input, answer.value());
+        }
+    }
+    answers.save()
+}
+
+fn watch_day(
+    args: WatchArgs,
+    year: u16,
+    input_dir: PathBuf,
+    key_file: Option<PathBuf>,
+) -> Result<(), Error> {
+    let watch = Watch {
+        year,
+        day: args.day,
+        parts: args.part,
+        test: args.test,
+        rebuild: args.build.then_some(args.root),
+        timeout: args.timeout,
+        interval: Duration::from_millis(args.interval),
+        answers: args.answers,
+    };
+    let source = InputSource::new(None, input_dir.clone(), key_file)?;
+    watch::watch(&registry(year)?, &watch, &input_dir, &source)
 }
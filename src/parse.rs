@@ -0,0 +1,91 @@
+//! Helpers for parsing puzzle input with useful errors.
+
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// A single line of puzzle input along with where it came from, so parsers can
+/// point errors at the exact text that was wrong.
+#[derive(Clone, Copy)]
+pub struct Line<'a> {
+    index: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub(crate) fn new(index: usize, text: &'a str) -> Self {
+        Line { index, text }
+    }
+
+    /// The whole line.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Builds an error pointing at `token`, which should be a slice of this
+    /// line. Anything else is reported at the end of the line.
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= self.text.len())
+            .unwrap_or(self.text.len());
+        ParseError::new(self.index + 1, offset + 1, self.text, token, message)
+    }
+
+    /// Splits `s` around the first `delimiter`, which must be there.
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: char,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("expected '{delimiter}'")))
+    }
+
+    /// Removes `prefix` from the start of `s`, which must be there.
+    pub fn strip_prefix(&self, s: &'a str, prefix: char) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected '{prefix}'")))
+    }
+
+    /// Removes `suffix` from the end of `s`, which must be there.
+    pub fn strip_suffix(&self, s: &'a str, suffix: char) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix)
+            .ok_or_else(|| self.error(s, format!("expected '{suffix}'")))
+    }
+
+    /// Parses a single number.
+    pub fn parse<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        str::parse::<T>(token).map_err(|_| self.error(token, format!("invalid number '{token}'")))
+    }
+
+    /// Parses every whitespace separated number in `s`.
+    pub fn parse_all<T: FromStr>(&self, s: &'a str) -> Result<Vec<T>, ParseError> {
+        s.split_ascii_whitespace()
+            .map(|token| self.parse(token))
+            .collect()
+    }
+}
+
+/// Every line of the input.
+pub fn lines(lines: &[String]) -> impl Iterator<Item = Line<'_>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Gets a single line, failing if the input ends before it.
+pub fn line(lines: &[String], index: usize) -> Result<Line<'_>, ParseError> {
+    match lines.get(index) {
+        Some(text) => Ok(Line { index, text }),
+        None => Err(ParseError::new(
+            index + 1,
+            1,
+            "",
+            "",
+            "unexpected end of input",
+        )),
+    }
+}
@@ -0,0 +1,550 @@
+//! Printing the results of runs, checks and benchmarks.
+
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    allocations::Allocations,
+    answers::Check,
+    bench::Benchmark,
+    differential::Comparison,
+    error::Error,
+    input::InputSource,
+    runner::{DaySelection, Outcome, PartSelection},
+    timings::{self, Regression, Timing, Timings},
+    validate::Validation,
+    Part,
+};
+
+/// How the results of a run are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A table for people, or just the answer when running a single part
+    Text,
+    /// An array with one object per day and part
+    Json,
+    /// A header followed by one row per day and part
+    Csv,
+}
+
+const ALLOCATION_HEADERS: [&str; 3] = ["Allocs", "Allocated", "Peak"];
+
+/// One day and part of a run, as printed by the machine-readable formats.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: &'a Part,
+    input: String,
+    status: &'static str,
+    label: Option<&'a str>,
+    answer: Option<String>,
+    error: Option<String>,
+    elapsed_ns: u128,
+    cached: bool,
+    allocations: Option<u64>,
+    allocated_bytes: Option<u64>,
+    peak_bytes: Option<u64>,
+}
+
+impl<'a> Record<'a> {
+    fn new(outcome: &'a Outcome, source: &InputSource) -> Self {
+        let (status, label, answer, error) = match &outcome.answer {
+            Ok(answer) => (
+                "ok",
+                Some(answer.label()),
+                Some(answer.value().to_string()),
+                None,
+            ),
+            Err(Error::NotImplemented(_)) => ("unimplemented", None, None, None),
+            Err(e @ Error::TimedOut(_)) => ("timeout", None, None, Some(e.to_string())),
+            Err(e) => ("error", None, None, Some(e.to_string())),
+        };
+        Record {
+            day: outcome.day,
+            part: &outcome.part,
+            input: source.name(outcome.day),
+            status,
+            label,
+            answer,
+            error,
+            elapsed_ns: outcome.elapsed.as_nanos(),
+            cached: outcome.cached,
+            allocations: outcome.allocations.map(|allocations| allocations.count),
+            allocated_bytes: outcome.allocations.map(|allocations| allocations.bytes),
+            peak_bytes: outcome.allocations.map(|allocations| allocations.peak),
+        }
+    }
+}
+
+/// Prints every outcome as a JSON array of records.
+pub fn print_json(outcomes: &[Outcome], source: &InputSource) {
+    let records = outcomes
+        .iter()
+        .map(|outcome| Record::new(outcome, source))
+        .collect::<Vec<Record>>();
+    println!("{}", serde_json::to_string_pretty(&records).unwrap());
+}
+
+/// Prints every outcome as a CSV record, with the same fields as the JSON.
+pub fn print_csv(outcomes: &[Outcome], source: &InputSource) {
+    println!(
+        "day,part,input,status,label,answer,error,elapsed_ns,cached,allocations,allocated_bytes,peak_bytes"
+    );
+    for outcome in outcomes {
+        let record = Record::new(outcome, source);
+        let fields = [
+            record.day.to_string(),
+            record.part.to_string(),
+            record.input,
+            record.status.to_string(),
+            record.label.unwrap_or_default().to_string(),
+            record.answer.unwrap_or_default(),
+            record.error.unwrap_or_default(),
+            record.elapsed_ns.to_string(),
+            record.cached.to_string(),
+            record.allocations.map_or(String::new(), |n| n.to_string()),
+            record
+                .allocated_bytes
+                .map_or(String::new(), |n| n.to_string()),
+            record.peak_bytes.map_or(String::new(), |n| n.to_string()),
+        ];
+        let fields = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<String>>();
+        println!("{}", fields.join(","));
+    }
+}
+
+/// Quotes a field if it contains anything that would otherwise end it early.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Prints one row per day and part with its answer and elapsed time, followed
+/// by the total time across every row. What each part allocated is shown too
+/// when allocations are being counted.
+pub fn print_table(outcomes: &[Outcome]) {
+    let counted = outcomes.iter().any(|outcome| outcome.allocations.is_some());
+    let rows = outcomes
+        .iter()
+        .map(|outcome| {
+            let mut row = vec![
+                outcome.day.to_string(),
+                outcome.part.to_string(),
+                answer_cell(outcome),
+                if outcome.cached {
+                    "cached".to_string()
+                } else {
+                    format!("{:.2?}", outcome.elapsed)
+                },
+            ];
+            if counted {
+                row.extend(allocation_cells(outcome.allocations));
+            }
+            row
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let mut headers = vec!["Day", "Part", "Answer", "Elapsed"];
+    let mut alignments = vec![Align::Right, Align::Left, Align::Left, Align::Right];
+    if counted {
+        headers.extend(ALLOCATION_HEADERS);
+        alignments.extend([Align::Right; 3]);
+    }
+    let widths = print_rows(&headers, &rows, &alignments);
+
+    let total = outcomes
+        .iter()
+        .map(|outcome| outcome.elapsed)
+        .sum::<Duration>();
+    // Lined up with the right of the elapsed column
+    let width = widths[..4].iter().sum::<usize>() + 3 * 2 - "Total".len();
+    println!("Total{:>width$}", format!("{total:.2?}"));
+
+    print_diagnostics(outcomes);
+}
+
+/// Prints each outcome next to the answer recorded for its input, with
+/// whether they matched.
+pub fn print_checks(checks: &[(Outcome, String, Option<Check>)]) {
+    let rows = checks
+        .iter()
+        .map(|(outcome, input, check)| {
+            let (expected, status) = match check {
+                Some(Check::Pass) => (answer_cell(outcome), "pass"),
+                Some(Check::Fail(expected)) => (expected.to_string(), "FAIL"),
+                Some(Check::Missing) => (String::new(), "missing"),
+                None if matches!(
+                    outcome.answer,
+                    Err(Error::NotImplemented(_) | Error::NoExamples(_))
+                ) =>
+                {
+                    (String::new(), "missing")
+                }
+                None => (String::new(), "FAIL"),
+            };
+            vec![
+                outcome.day.to_string(),
+                outcome.part.to_string(),
+                input.clone(),
+                answer_cell(outcome),
+                expected,
+                status.to_string(),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let headers = ["Day", "Part", "Input", "Answer", "Expected", "Status"];
+    let alignments = [
+        Align::Right,
+        Align::Left,
+        Align::Left,
+        Align::Left,
+        Align::Left,
+        Align::Left,
+    ];
+    print_rows(&headers, &rows, &alignments);
+
+    let outcomes = checks
+        .iter()
+        .map(|(outcome, _, _)| outcome)
+        .collect::<Vec<&Outcome>>();
+    print_diagnostics(outcomes);
+}
+
+/// Prints parse and solve timing statistics for each benchmarked part, along
+/// with what each allocated when allocations are being counted.
+pub fn print_bench(benchmarks: &[Benchmark]) {
+    let counted = benchmarks.iter().any(|benchmark| {
+        benchmark
+            .timings
+            .as_ref()
+            .is_ok_and(|(parse, _)| parse.allocations.is_some())
+    });
+    let mut rows = Vec::new();
+    for benchmark in benchmarks {
+        let (day, part) = (benchmark.day.to_string(), benchmark.part.to_string());
+        match &benchmark.timings {
+            Ok((parse, solve)) => {
+                for (stage, stats) in [("parse", parse), ("solve", solve)] {
+                    let mut row = vec![
+                        day.clone(),
+                        part.clone(),
+                        stage.to_string(),
+                        format!("{:.2?}", stats.min),
+                        format!("{:.2?}", stats.median),
+                        format!("{:.2?}", stats.mean),
+                        format!("{:.2?}", stats.p95),
+                    ];
+                    if counted {
+                        row.extend(allocation_cells(stats.allocations));
+                    }
+                    rows.push(row);
+                }
+            }
+            Err(Error::NotImplemented(_)) => {
+                rows.push(vec![day, part, "not implemented".to_string()])
+            }
+            Err(e) => rows.push(vec![day, part, error_cell(e)]),
+        }
+    }
+
+    let mut headers = vec!["Day", "Part", "Stage", "Min", "Median", "Mean", "P95"];
+    let mut alignments = vec![
+        Align::Right,
+        Align::Left,
+        Align::Left,
+        Align::Right,
+        Align::Right,
+        Align::Right,
+        Align::Right,
+    ];
+    if counted {
+        headers.extend(ALLOCATION_HEADERS);
+        alignments.extend([Align::Right; 3]);
+    }
+    print_rows(&headers, &rows, &alignments);
+}
+
+/// Prints whether each assumption held for each day's input, explaining any
+/// that didn't.
+pub fn print_validations(validations: &[Validation]) {
+    let mut rows = Vec::new();
+    for validation in validations {
+        let day = validation.day.to_string();
+        match &validation.results {
+            Ok(results) if results.is_empty() => {
+                rows.push(vec![day, "no assumptions declared".to_string()])
+            }
+            Ok(results) => {
+                for (description, result) in results {
+                    let status = match result {
+                        Ok(()) => "ok".to_string(),
+                        Err(reason) => format!("FAIL: {reason}"),
+                    };
+                    rows.push(vec![day.clone(), description.to_string(), status]);
+                }
+            }
+            Err(Error::NotImplemented(_)) => rows.push(vec![day, "not implemented".to_string()]),
+            Err(e) => rows.push(vec![day, String::new(), error_cell(e)]),
+        }
+    }
+
+    print_rows(
+        &["Day", "Assumption", "Status"],
+        &rows,
+        &[Align::Right, Align::Left, Align::Left],
+    );
+
+    for validation in validations {
+        if let Err(e @ Error::Parse(_)) = &validation.results {
+            eprintln!("\nerror: {e}");
+        }
+    }
+}
+
+/// Prints whether each part agreed with its reference solution, then the
+/// first input that any part disagreed on in full, with `size` for
+/// regenerating it.
+pub fn print_comparisons(comparisons: &[Comparison], size: usize) {
+    let rows = comparisons
+        .iter()
+        .map(|comparison| {
+            let status = match &comparison.result {
+                Ok(None) => "agree".to_string(),
+                Ok(Some(disagreement)) => format!("DISAGREE on seed {}", disagreement.seed),
+                Err(Error::NotImplemented(_)) => "not implemented".to_string(),
+                Err(Error::NoGenerator(_)) => "no generator".to_string(),
+                Err(Error::NoReference(_)) => "no reference".to_string(),
+                Err(e) => error_cell(e),
+            };
+            vec![
+                comparison.day.to_string(),
+                comparison.part.to_string(),
+                comparison.inputs.to_string(),
+                status,
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    print_rows(
+        &["Day", "Part", "Inputs", "Status"],
+        &rows,
+        &[Align::Right, Align::Left, Align::Right, Align::Left],
+    );
+
+    for comparison in comparisons {
+        let Ok(Some(disagreement)) = &comparison.result else {
+            continue;
+        };
+        let show = |result: &Result<_, Error>| match result {
+            Ok(value) => format!("{value}"),
+            Err(e) => format!("error: {e}"),
+        };
+        println!(
+            "\nDay {} part {} on seed {} (generate -d {} -s {} --size {size}):",
+            comparison.day, comparison.part, disagreement.seed, comparison.day, disagreement.seed
+        );
+        println!("  answer:   {}", show(&disagreement.answer));
+        println!("  expected: {}", show(&disagreement.expected));
+        println!("  input:");
+        for line in &disagreement.input {
+            println!("    {line}");
+        }
+    }
+}
+
+/// Prints a row for each selected part that has been benchmarked, comparing its
+/// latest time with its baseline from before then, and sketching how its time
+/// has changed over its recent runs.
+pub fn print_timings(timings: &Timings, days: &DaySelection, parts: &PartSelection) {
+    let mut rows = Vec::new();
+    for &day in days.days() {
+        for part in parts.parts() {
+            let runs = timings.runs(day, &part);
+            let Some((latest, earlier)) = runs.split_last() else {
+                continue;
+            };
+            let baseline = timings::baseline(earlier);
+            let change = baseline.map(|baseline| {
+                let change = latest.median.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+                format!("{:+.1}%", change * 100.0)
+            });
+            rows.push(vec![
+                day.to_string(),
+                part.to_string(),
+                runs.len().to_string(),
+                format!("{:.2?}", runs.iter().map(|run| run.median).min().unwrap()),
+                baseline.map_or(String::new(), |baseline| format!("{baseline:.2?}")),
+                format!("{:.2?}", latest.median),
+                change.unwrap_or_default(),
+                latest.commit.clone().unwrap_or_default(),
+                sparkline(&runs),
+            ]);
+        }
+    }
+    if rows.is_empty() {
+        println!("Nothing has been benchmarked yet");
+        return;
+    }
+
+    let headers = [
+        "Day", "Part", "Runs", "Best", "Baseline", "Latest", "Change", "Commit", "Trend",
+    ];
+    let alignments = [
+        Align::Right,
+        Align::Left,
+        Align::Right,
+        Align::Right,
+        Align::Right,
+        Align::Right,
+        Align::Right,
+        Align::Left,
+        Align::Left,
+    ];
+    print_rows(&headers, &rows, &alignments);
+}
+
+/// A bar for each of the most recent runs, from the fastest of them to the
+/// slowest.
+fn sparkline(runs: &[&Timing]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let recent = &runs[runs.len().saturating_sub(20)..];
+    let min = recent.iter().map(|run| run.median).min().unwrap();
+    let max = recent.iter().map(|run| run.median).max().unwrap();
+    let range = (max - min).as_secs_f64();
+    recent
+        .iter()
+        .map(|run| {
+            let height = if range > 0.0 {
+                (run.median - min).as_secs_f64() / range
+            } else {
+                0.0
+            };
+            BARS[(height * (BARS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
+/// Explains how much slower each regressed part was than its baseline.
+pub fn print_regressions(regressions: &[Regression]) {
+    for regression in regressions {
+        let slowdown = regression.median.as_secs_f64() / regression.baseline.as_secs_f64() - 1.0;
+        println!(
+            "Day {} part {} took {:.2?}, {:.1}% slower than its baseline of {:.2?}",
+            regression.day,
+            regression.part,
+            regression.median,
+            slowdown * 100.0,
+            regression.baseline
+        );
+    }
+}
+
+/// A number of bytes in the largest binary unit that keeps it at least one.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// How a column's cells line up.
+#[derive(Clone, Copy)]
+enum Align {
+    Left,
+    Right,
+}
+
+/// Prints `rows` under `headers`, with each column as wide as its widest cell
+/// and aligned as `alignments` says. Rows may leave off cells at the end.
+/// Returns the widths of the columns, for lining up anything printed after.
+fn print_rows(headers: &[&str], rows: &[Vec<String>], alignments: &[Align]) -> Vec<usize> {
+    let widths = (0..headers.len())
+        .map(|col| {
+            rows.iter()
+                .filter_map(|row| row.get(col))
+                .map(|cell| cell.chars().count())
+                .chain([headers[col].len()])
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<usize>>();
+    let print_row = |cells: &mut dyn Iterator<Item = &str>| {
+        let line = cells
+            .zip(widths.iter().zip(alignments))
+            .map(|(cell, (&width, align))| match align {
+                Align::Left => format!("{cell:<width$}"),
+                Align::Right => format!("{cell:>width$}"),
+            })
+            .collect::<Vec<String>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+
+    print_row(&mut headers.iter().copied());
+    for row in rows {
+        print_row(&mut row.iter().map(String::as_str));
+    }
+    widths
+}
+
+fn answer_cell(outcome: &Outcome) -> String {
+    match &outcome.answer {
+        Ok(answer) => answer.value().to_string(),
+        Err(Error::NotImplemented(_)) => "not implemented".to_string(),
+        Err(Error::NoExamples(_)) => "no examples".to_string(),
+        Err(Error::TimedOut(timeout)) => format!("timed out after {timeout:.2?}"),
+        Err(e) => error_cell(e),
+    }
+}
+
+/// The first line of an error, which is all that fits in a table cell.
+fn error_cell(e: &Error) -> String {
+    format!(
+        "error: {}",
+        e.to_string().lines().next().unwrap_or_default()
+    )
+}
+
+/// What a part allocated, or empty cells if allocations weren't counted.
+fn allocation_cells(allocations: Option<Allocations>) -> [String; 3] {
+    match allocations {
+        Some(allocations) => [
+            allocations.count.to_string(),
+            format_bytes(allocations.bytes),
+            format_bytes(allocations.peak),
+        ],
+        None => Default::default(),
+    }
+}
+
+/// Diagnostics don't fit in a table cell, so show them in full underneath.
+/// Both parts of a day share its parse, so a diagnostic they share is only
+/// shown once.
+fn print_diagnostics<'a>(outcomes: impl IntoIterator<Item = &'a Outcome>) {
+    let mut shown = Vec::new();
+    for outcome in outcomes {
+        if let Err(e @ Error::Parse(_)) = &outcome.answer {
+            let diagnostic = e.to_string();
+            if !shown.contains(&diagnostic) {
+                eprintln!("\nerror: {diagnostic}");
+                shown.push(diagnostic);
+            }
+        }
+    }
+}
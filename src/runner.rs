@@ -0,0 +1,361 @@
+//! Running days and parts, and choosing which to run.
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::{mpsc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use clap::ValueEnum;
+
+use crate::{
+    allocations::{self, Allocations},
+    answer::Answer,
+    cache::{Cache, Key},
+    cancel::{self, Cancelled},
+    error::Error,
+    input::{InputSource, RawInput},
+    solver::Registry,
+    Part,
+};
+
+/// The days to run, written as a comma separated list of days and inclusive
+/// ranges, e.g. `1-5,8`.
+#[derive(Clone, Debug)]
+pub struct DaySelection(Vec<u8>);
+
+impl DaySelection {
+    /// Every day in `registry`.
+    pub fn all(registry: &Registry) -> Self {
+        DaySelection(registry.days().collect())
+    }
+
+    /// Just `day`.
+    pub fn single(day: u8) -> Self {
+        DaySelection(vec![day])
+    }
+
+    /// The selected days, in order and without duplicates.
+    pub fn days(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            let day = str::parse::<u8>(day.trim()).map_err(|_| format!("invalid day '{day}'"))?;
+            if (1..=25).contains(&day) {
+                Ok(day)
+            } else {
+                Err(format!("day {day} is not between 1 and 25"))
+            }
+        };
+
+        let mut days = Vec::new();
+        for item in s.split(',') {
+            if let Some((start, end)) = item.split_once('-') {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("range '{item}' is backwards"));
+                }
+                days.extend(start..=end);
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+        days.sort();
+        days.dedup();
+        Ok(DaySelection(days))
+    }
+}
+
+/// Which parts of each day to run.
+#[derive(Clone, Debug, ValueEnum)]
+pub enum PartSelection {
+    /// Only the first part
+    One,
+    /// Only the second part
+    Two,
+    /// Both parts, first then second
+    Both,
+}
+
+impl PartSelection {
+    /// The selected parts, in order.
+    pub fn parts(&self) -> Vec<Part> {
+        match self {
+            PartSelection::One => vec![Part::One],
+            PartSelection::Two => vec![Part::Two],
+            PartSelection::Both => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+/// The result of running a single part of a single day.
+pub struct Outcome {
+    /// The day that was run
+    pub day: u8,
+    /// The part that was run
+    pub part: Part,
+    /// The answer, or why there isn't one
+    pub answer: Result<Answer, Error>,
+    /// How long parsing and solving took, or zero if they didn't finish
+    pub elapsed: Duration,
+    /// Whether the answer came from the cache instead of being solved
+    pub cached: bool,
+    /// What parsing and solving allocated, if allocations are being counted
+    pub allocations: Option<Allocations>,
+}
+
+/// A part that was solved, and what solving it took.
+pub struct Solved {
+    /// The part's answer
+    pub answer: Answer,
+    /// How long parsing and solving took
+    pub elapsed: Duration,
+    /// What parsing and solving allocated, if allocations are being counted
+    pub allocations: Option<Allocations>,
+}
+
+/// Runs every selected part of every selected day on up to `jobs` threads,
+/// carrying on past any that fail. Outcomes are in day and part order however
+/// many jobs there are. Answers are taken from `cache` when it has them, and
+/// added to it when it doesn't.
+pub fn run_selection(
+    registry: &Registry,
+    days: &DaySelection,
+    parts: &PartSelection,
+    source: &InputSource,
+    timeout: Option<Duration>,
+    jobs: usize,
+    cache: Option<&Cache>,
+) -> Vec<Outcome> {
+    let tasks = days
+        .days()
+        .iter()
+        .flat_map(|&day| parts.parts().into_iter().map(move |part| (day, part)))
+        .collect::<Vec<(u8, Part)>>();
+    run_parallel(tasks, jobs, |(day, part)| {
+        let result = match cache {
+            Some(cache) => run_cached(registry, day, &part, source, timeout, cache),
+            None => run_part(registry, day, &part, source, timeout).map(|solved| (solved, false)),
+        };
+        match result {
+            Ok((solved, cached)) => Outcome {
+                day,
+                part,
+                answer: Ok(solved.answer),
+                elapsed: solved.elapsed,
+                cached,
+                allocations: solved.allocations,
+            },
+            Err(e) => Outcome {
+                day,
+                part,
+                answer: Err(e),
+                elapsed: Duration::ZERO,
+                cached: false,
+                allocations: None,
+            },
+        }
+    })
+}
+
+/// Calls `f` on every task using up to `jobs` threads, returning the results in
+/// the same order as the tasks.
+pub fn run_parallel<T: Send, R: Send>(
+    tasks: Vec<T>,
+    jobs: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R> {
+    if jobs <= 1 {
+        return tasks.into_iter().map(f).collect();
+    }
+
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let mut results = thread::scope(|scope| {
+        let workers = (0..jobs)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        let Some((index, task)) = queue.lock().unwrap().next() else {
+                            break;
+                        };
+                        results.push((index, f(task)));
+                    }
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Vec<(usize, R)>>()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Runs a single part, timing how long it takes to parse and solve, and giving
+/// up once `timeout` has passed.
+pub fn run_part(
+    registry: &Registry,
+    day: u8,
+    part: &Part,
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Result<Solved, Error> {
+    registry.get(day)?;
+    run_input(registry, day, part, source.open(day)?, timeout)
+}
+
+/// Runs a single part like [`run_part`] unless `cache` already has its answer,
+/// caching the answer if it's solved. Also says whether the answer came from
+/// the cache. The input is read once to look it up in the cache and again to
+/// solve it, so that neither has to hold all of it.
+fn run_cached(
+    registry: &Registry,
+    day: u8,
+    part: &Part,
+    source: &InputSource,
+    timeout: Option<Duration>,
+    cache: &Cache,
+) -> Result<(Solved, bool), Error> {
+    let version = registry.get(day)?.version();
+    let mut input = source.open(day)?;
+    let key = Key::new(day, part, &mut input, version);
+    input.finish()?;
+    if let Some(answer) = cache.get(&key) {
+        let solved = Solved {
+            answer,
+            elapsed: Duration::ZERO,
+            allocations: None,
+        };
+        return Ok((solved, true));
+    }
+    let solved = run_part(registry, day, part, source, timeout)?;
+    cache.insert(key, &solved.answer);
+    Ok((solved, false))
+}
+
+/// Runs a single part on input that has already been opened.
+pub fn run_input(
+    registry: &Registry,
+    day: u8,
+    part: &Part,
+    input: RawInput,
+    timeout: Option<Duration>,
+) -> Result<Solved, Error> {
+    let solver = *registry.get(day)?;
+    let part = part.clone();
+    let run = move || {
+        // A panicking solver shouldn't take the rest of the calendar down with it
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            let before = Instant::now();
+            let (answer, allocations) = allocations::measure(|| solver.run(input, &part));
+            (answer, before.elapsed(), allocations)
+        }))
+    };
+
+    let result = match timeout {
+        None => run(),
+        Some(timeout) => {
+            // The solver runs on its own thread so that one which never checks
+            // for cancellation can be left behind instead of hanging the run
+            let (sender, receiver) = mpsc::channel();
+            let deadline = Instant::now() + timeout;
+            thread::spawn(move || {
+                // Nobody is listening any more if the solver took too long
+                let _ = sender.send(cancel::with_deadline(deadline, run));
+            });
+            receiver
+                .recv_timeout(timeout)
+                .map_err(|_| Error::TimedOut(timeout))?
+        }
+    };
+    let (answer, elapsed, allocations) = result.map_err(|payload| {
+        if payload.is::<Cancelled>() {
+            Error::TimedOut(timeout.unwrap_or_default())
+        } else {
+            Error::Panicked(panic_message(payload.as_ref()))
+        }
+    })?;
+    Ok(Solved {
+        answer: answer?,
+        elapsed,
+        allocations,
+    })
+}
+
+/// The message passed to `panic!`, or nothing if it wasn't a string.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(s: &str) -> Result<Vec<u8>, String> {
+        str::parse::<DaySelection>(s).map(|selection| selection.0)
+    }
+
+    #[test]
+    fn parses_single_days() {
+        assert_eq!(days("3"), Ok(vec![3]));
+        assert_eq!(days(" 7 "), Ok(vec![7]));
+    }
+
+    #[test]
+    fn parses_ranges_and_lists() {
+        assert_eq!(days("1-5,8"), Ok(vec![1, 2, 3, 4, 5, 8]));
+        assert_eq!(days("4-4"), Ok(vec![4]));
+        assert_eq!(days("1 - 3"), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn sorts_and_removes_duplicates() {
+        assert_eq!(days("8,2-4,3,8"), Ok(vec![2, 3, 4, 8]));
+    }
+
+    #[test]
+    fn rejects_days_outside_december() {
+        assert_eq!(days("0"), Err("day 0 is not between 1 and 25".to_string()));
+        assert_eq!(
+            days("26"),
+            Err("day 26 is not between 1 and 25".to_string())
+        );
+        assert_eq!(
+            days("20-26"),
+            Err("day 26 is not between 1 and 25".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_backwards_ranges() {
+        assert_eq!(days("5-3"), Err("range '5-3' is backwards".to_string()));
+    }
+
+    #[test]
+    fn rejects_malformed_selections() {
+        assert_eq!(days(""), Err("invalid day ''".to_string()));
+        assert_eq!(days("1,"), Err("invalid day ''".to_string()));
+        assert_eq!(days(",1"), Err("invalid day ''".to_string()));
+        assert_eq!(days("1-"), Err("invalid day ''".to_string()));
+        assert_eq!(days("-3"), Err("invalid day ''".to_string()));
+        assert_eq!(days("1-2-3"), Err("invalid day '2-3'".to_string()));
+        assert_eq!(days("x"), Err("invalid day 'x'".to_string()));
+        assert_eq!(days("256"), Err("invalid day '256'".to_string()));
+    }
+}
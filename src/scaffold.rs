@@ -0,0 +1,318 @@
+//! Creating a new day from the template.
+
+use std::path::Path;
+
+use crate::{error::Error, examples};
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+const EXAMPLES_TEMPLATE: &str = "[[example]]
+name = \"example\"
+input = '''
+'''
+# part_one = \"\"
+# part_two = \"\"
+";
+
+/// Creates `src/year{Y}/day{N}.rs` from the template inside the crate at
+/// `root`, declares and registers it in `src/year{Y}.rs`, and creates empty
+/// input and examples files for it. The year's module is created and
+/// registered in `src/lib.rs` too if this is its first day. Nothing is changed
+/// if the day already exists, and input files that are already there are left
+/// alone.
+pub fn scaffold(root: &Path, input_dir: &Path, year: u16, day: u8) -> Result<(), Error> {
+    let src = root.join("src");
+    let day_path = src.join(format!("year{year}")).join(format!("day{day}.rs"));
+    if day_path.exists() {
+        return Err(Error::Scaffold(format!(
+            "'{}' already exists",
+            day_path.display()
+        )));
+    }
+
+    let year_path = src.join(format!("year{year}.rs"));
+    let lib_path = src.join("lib.rs");
+    let mut edits = Vec::new();
+    if year_path.exists() {
+        let year_source = read(&year_path)?;
+        let year_source = declare_day(&year_source, day)
+            .and_then(|year_source| register_day(&year_source, day))
+            .map_err(|reason| Error::Scaffold(format!("{reason} in '{}'", year_path.display())))?;
+        edits.push((year_path, year_source));
+    } else {
+        let lib = read(&lib_path)?;
+        let lib = declare_year(&lib, year)
+            .and_then(|lib| register_year(&lib, year))
+            .map_err(|reason| Error::Scaffold(format!("{reason} in '{}'", lib_path.display())))?;
+        edits.push((year_path, year_source(year, day)));
+        edits.push((lib_path, lib));
+    }
+
+    write(&day_path, &day_source(day))?;
+    println!("Created {}", day_path.display());
+    for (path, contents) in edits {
+        write(&path, &contents)?;
+        println!("Updated {}", path.display());
+    }
+    for (path, contents) in [
+        (input_dir.join(format!("day{day}.txt")), ""),
+        (examples::path(input_dir, day), EXAMPLES_TEMPLATE),
+    ] {
+        if !path.exists() {
+            write(&path, contents)?;
+            println!("Created {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// The module for a new year, with `day` as its only day so far.
+fn year_source(year: u16, day: u8) -> String {
+    format!(
+        "//! Solutions to the {year} puzzles.
+
+pub mod day{day};
+
+use crate::solver::Registry;
+
+/// Every implemented day of {year}.
+pub fn registry() -> Registry {{
+    let mut registry = Registry::new();
+    registry.register::<day{day}::Day{day}>();
+    registry
+}}
+"
+    )
+}
+
+fn day_source(day: u8) -> String {
+    TEMPLATE
+        .replace("#![allow(dead_code)]\n\n", "")
+        .replace("DayN", &format!("Day{day}"))
+        .replace("Day N", &format!("Day {day}"))
+        .replace("day N", &format!("day {day}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+/// Adds `pub mod day{N};` to the year's other days' declarations, keeping them in the
+/// order rustfmt wants.
+fn declare_day(lib: &str, day: u8) -> Result<String, String> {
+    let declaration = format!("pub mod day{day};");
+    if lib.lines().any(|line| line == declaration) {
+        return Err(format!("day {day} is already declared"));
+    }
+    insert_line(lib, &declaration, |line| {
+        line.strip_prefix("pub mod day")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .map(|day| format!("day{day}"))
+    })
+    .ok_or_else(|| "couldn't find the day modules".to_string())
+}
+
+/// Adds the day to the registry after the days before it.
+fn register_day(lib: &str, day: u8) -> Result<String, String> {
+    let registration = format!("    registry.register::<day{day}::Day{day}>();");
+    insert_line(lib, &registration, |line| {
+        line.trim_start()
+            .strip_prefix("registry.register::<day")
+            .and_then(|rest| rest.split_once(':'))
+            .map(|(day, _)| format!("{day:0>2}"))
+    })
+    .ok_or_else(|| "couldn't find the registry".to_string())
+}
+
+/// Adds `pub mod year{Y};` to the other years' declarations.
+fn declare_year(lib: &str, year: u16) -> Result<String, String> {
+    insert_line(lib, &format!("pub mod year{year};"), |line| {
+        line.strip_prefix("pub mod year")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .map(str::to_string)
+    })
+    .ok_or_else(|| "couldn't find the year modules".to_string())
+}
+
+/// Adds the year's registry to the match in `registry`.
+fn register_year(lib: &str, year: u16) -> Result<String, String> {
+    let arm = format!("        {year} => Ok(year{year}::registry()),");
+    insert_line(lib, &arm, |line| {
+        line.trim_start()
+            .split_once(" => Ok(year")
+            .map(|(year, _)| year.to_string())
+    })
+    .ok_or_else(|| "couldn't find the registry".to_string())
+}
+
+/// Inserts `new` into the first run of consecutive lines that `key` gives a
+/// sort key for, before the first line whose key sorts after it.
+fn insert_line(text: &str, new: &str, key: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let new_key = key(new)?;
+    let mut lines = text.lines().collect::<Vec<&str>>();
+    let start = lines.iter().position(|line| key(line).is_some())?;
+    let end = lines[start..]
+        .iter()
+        .position(|line| key(line).is_none())
+        .map_or(lines.len(), |len| start + len);
+    let index = lines[start..end]
+        .iter()
+        .position(|line| key(line).unwrap() > new_key)
+        .map_or(end, |offset| start + offset);
+    lines.insert(index, new);
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|e| Error::Input(path.display().to_string(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    path.parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(path, contents))
+        .map_err(|e| Error::Write(path.display().to_string(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: &str = "//! Solutions to the 2023 puzzles.
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+
+use crate::solver::Registry;
+
+/// Every implemented day of 2023.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register::<day1::Day1>();
+    registry.register::<day2::Day2>();
+    registry.register::<day10::Day10>();
+    registry.register::<day11::Day11>();
+    registry
+}
+";
+
+    const LIB: &str = "pub mod solver;
+pub mod year2023;
+
+/// Every implemented day of `year`.
+pub fn registry(year: u16) -> Result<Registry, Error> {
+    match year {
+        2023 => Ok(year2023::registry()),
+        _ => Err(Error::UnknownYear(year)),
+    }
+}
+";
+
+    /// The lines of `text` that contain `pattern`.
+    fn matching<'a>(text: &'a str, pattern: &str) -> Vec<&'a str> {
+        text.lines().filter(|line| line.contains(pattern)).collect()
+    }
+
+    #[test]
+    fn declares_days_in_rustfmt_order() {
+        let year = declare_day(YEAR, 3).unwrap();
+        assert_eq!(
+            matching(&year, "pub mod"),
+            [
+                "pub mod day1;",
+                "pub mod day10;",
+                "pub mod day11;",
+                "pub mod day2;",
+                "pub mod day3;"
+            ]
+        );
+        let year = declare_day(YEAR, 12).unwrap();
+        assert_eq!(
+            matching(&year, "pub mod"),
+            [
+                "pub mod day1;",
+                "pub mod day10;",
+                "pub mod day11;",
+                "pub mod day12;",
+                "pub mod day2;"
+            ]
+        );
+    }
+
+    #[test]
+    fn registers_days_in_numeric_order() {
+        let year = register_day(YEAR, 3).unwrap();
+        assert_eq!(
+            matching(&year, "register::"),
+            [
+                "    registry.register::<day1::Day1>();",
+                "    registry.register::<day2::Day2>();",
+                "    registry.register::<day3::Day3>();",
+                "    registry.register::<day10::Day10>();",
+                "    registry.register::<day11::Day11>();"
+            ]
+        );
+        let year = register_day(YEAR, 25).unwrap();
+        assert_eq!(
+            matching(&year, "register::").last(),
+            Some(&"    registry.register::<day25::Day25>();")
+        );
+    }
+
+    #[test]
+    fn leaves_the_rest_of_the_file_alone() {
+        let year = register_day(&declare_day(YEAR, 3).unwrap(), 3).unwrap();
+        let added = ["pub mod day3;", "    registry.register::<day3::Day3>();"];
+        assert_eq!(
+            year.lines()
+                .filter(|line| !added.contains(line))
+                .collect::<Vec<_>>(),
+            YEAR.lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn refuses_days_that_are_already_declared() {
+        assert_eq!(
+            declare_day(YEAR, 10),
+            Err("day 10 is already declared".to_string())
+        );
+    }
+
+    #[test]
+    fn adds_a_new_year() {
+        let lib = register_year(&declare_year(LIB, 2024).unwrap(), 2024).unwrap();
+        assert_eq!(
+            matching(&lib, "pub mod"),
+            ["pub mod solver;", "pub mod year2023;", "pub mod year2024;"]
+        );
+        assert_eq!(
+            matching(&lib, "=>"),
+            [
+                "        2023 => Ok(year2023::registry()),",
+                "        2024 => Ok(year2024::registry()),",
+                "        _ => Err(Error::UnknownYear(year)),"
+            ]
+        );
+
+        let lib = register_year(&declare_year(LIB, 2022).unwrap(), 2022).unwrap();
+        assert_eq!(
+            matching(&lib, "=> Ok"),
+            [
+                "        2022 => Ok(year2022::registry()),",
+                "        2023 => Ok(year2023::registry()),"
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_without_anywhere_to_add_to() {
+        assert_eq!(
+            declare_day(LIB, 1),
+            Err("couldn't find the day modules".to_string())
+        );
+        assert_eq!(
+            register_year(YEAR, 2024),
+            Err("couldn't find the registry".to_string())
+        );
+    }
+}
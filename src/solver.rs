@@ -0,0 +1,217 @@
+//! The interface each day implements, and the registry of days.
+
+use std::{any::Any, collections::BTreeMap};
+
+use crate::{
+    answer::{Answer, Value},
+    error::{Error, ParseError},
+    input::RawInput,
+    rng::Rng,
+    Part,
+};
+
+/// A single day's puzzle: how to parse its input, and how to solve each part
+/// from the parsed input.
+pub trait Solver {
+    /// The day of December this puzzle is from
+    const DAY: u8;
+
+    /// The parsed input, shared by both parts
+    type Input: 'static;
+
+    /// Parses the lines of the puzzle input.
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError>;
+
+    /// Parses the puzzle input in whichever form suits the day, which is how
+    /// the runner parses it. Days that don't need every line at once can
+    /// override this to read a line at a time. By default it's all read into
+    /// lines for [`parse`](Solver::parse).
+    fn parse_raw(input: &mut RawInput) -> Result<Self::Input, ParseError> {
+        Self::parse(input.take_lines())
+    }
+
+    /// Solves the first part.
+    fn part_one(input: &Self::Input) -> Answer;
+
+    /// Solves the second part.
+    fn part_two(input: &Self::Input) -> Answer;
+
+    /// What the solutions rely on about the input beyond what `parse` checks.
+    const ASSUMPTIONS: &'static [Assumption<Self::Input>] = &[];
+
+    /// Makes up new inputs, for stress testing beyond the one real input.
+    const GENERATOR: Option<Generator> = None;
+
+    /// Slow but obviously correct solutions to check the real ones against.
+    const REFERENCE: Option<Reference> = None;
+
+    /// Identifies this version of the solutions, so that answers cached from
+    /// an older one are solved again. Bump it whenever a change could change
+    /// an answer.
+    const VERSION: u32 = 1;
+}
+
+/// Makes up an input of roughly `size` lines, or `size` wide for grids, out of
+/// the random numbers from `rng`. What the size means exactly is up to each
+/// day.
+pub type Generator = fn(rng: &mut Rng, size: usize) -> Vec<String>;
+
+/// Solutions that favor being obviously correct over being fast or clever.
+/// They work straight from the lines of the input, so they check the parsing
+/// too.
+#[derive(Clone, Copy)]
+pub struct Reference {
+    /// Solves the first part
+    pub part_one: fn(&[String]) -> Value,
+    /// Solves the second part
+    pub part_two: fn(&[String]) -> Value,
+}
+
+/// Something a solution relies on that holds for real inputs but that parsing
+/// doesn't guarantee, such as a path always looping back on itself.
+pub struct Assumption<I> {
+    /// What is assumed
+    pub description: &'static str,
+    /// Explains how the input breaks the assumption, if it does
+    pub check: fn(&I) -> Result<(), String>,
+}
+
+type ParseFn = fn(RawInput) -> Result<Box<dyn Any>, Error>;
+/// An assumption's description, and whether it held for an input.
+pub type CheckedAssumption = (&'static str, Result<(), String>);
+
+type ValidateFn = fn(&dyn Any) -> Vec<CheckedAssumption>;
+
+/// A registered day with its solver's types erased, so days with different
+/// `Input` types can live side by side in the `Registry`.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    day: u8,
+    parse: ParseFn,
+    solve: fn(&dyn Any, &Part) -> Answer,
+    validate: ValidateFn,
+    generator: Option<Generator>,
+    reference: Option<Reference>,
+    version: u32,
+}
+
+impl Entry {
+    fn new<S: Solver>() -> Self {
+        Entry {
+            day: S::DAY,
+            parse: parse_erased::<S>,
+            solve: solve_erased::<S>,
+            validate: validate_erased::<S>,
+            generator: S::GENERATOR,
+            reference: S::REFERENCE,
+            version: S::VERSION,
+        }
+    }
+
+    /// Parses the input and solves `part` of it, failing if the part hasn't
+    /// been solved yet.
+    pub fn run(&self, input: RawInput, part: &Part) -> Result<Answer, Error> {
+        let input = self.parse(input)?;
+        let answer = self.solve(input.as_ref(), part);
+        if answer.is_implemented() {
+            Ok(answer)
+        } else {
+            Err(Error::NotImplemented(self.day))
+        }
+    }
+
+    /// Parses the input into the solver's own `Input` type, which can only be
+    /// passed back into this entry's `solve`. Parse errors are reported
+    /// against the input's name.
+    pub fn parse(&self, input: RawInput) -> Result<Box<dyn Any>, Error> {
+        (self.parse)(input)
+    }
+
+    /// Solves `part` of input returned by this entry's `parse`.
+    pub fn solve(&self, input: &dyn Any, part: &Part) -> Answer {
+        (self.solve)(input, part)
+    }
+
+    /// Checks each of the solver's assumptions against input returned by this
+    /// entry's `parse`, giving each one's description and whether it held.
+    pub fn validate(&self, input: &dyn Any) -> Vec<CheckedAssumption> {
+        (self.validate)(input)
+    }
+
+    /// The solver's [`VERSION`](Solver::VERSION).
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Makes up an input of roughly `size`, if the solver has a generator.
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> Option<Vec<String>> {
+        self.generator.map(|generate| generate(rng, size))
+    }
+
+    /// Solves `part` with the reference solutions, if the solver has them.
+    pub fn solve_reference(&self, lines: &[String], part: &Part) -> Option<Value> {
+        self.reference.map(|reference| match part {
+            Part::One => (reference.part_one)(lines),
+            Part::Two => (reference.part_two)(lines),
+        })
+    }
+}
+
+fn parse_erased<S: Solver>(mut input: RawInput) -> Result<Box<dyn Any>, Error> {
+    let parsed = S::parse_raw(&mut input);
+    // Input that couldn't be read to the end usually fails to parse too, but
+    // it's the read error that explains why
+    input.finish()?;
+    match parsed {
+        Ok(parsed) => Ok(Box::new(parsed)),
+        Err(e) => Err(e.in_file(S::DAY, input.name()).into()),
+    }
+}
+
+fn solve_erased<S: Solver>(input: &dyn Any, part: &Part) -> Answer {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input to have been parsed by the same solver");
+    match part {
+        Part::One => S::part_one(input),
+        Part::Two => S::part_two(input),
+    }
+}
+
+fn validate_erased<S: Solver>(input: &dyn Any) -> Vec<CheckedAssumption> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input to have been parsed by the same solver");
+    S::ASSUMPTIONS
+        .iter()
+        .map(|assumption| (assumption.description, (assumption.check)(input)))
+        .collect()
+}
+
+/// The solvers for each day, looked up by day number.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u8, Entry>,
+}
+
+impl Registry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Adds `S`, replacing any solver already registered for its day.
+    pub fn register<S: Solver>(&mut self) {
+        self.days.insert(S::DAY, Entry::new::<S>());
+    }
+
+    /// The solver for `day`, if one has been registered.
+    pub fn get(&self, day: u8) -> Result<&Entry, Error> {
+        self.days.get(&day).ok_or(Error::NotImplemented(day))
+    }
+
+    /// Every registered day, in order.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+}
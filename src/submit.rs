@@ -0,0 +1,402 @@
+//! Submitting answers and remembering how they went.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{answer::Value, client::Client, error::Error, tsv, Part};
+
+/// How the website responded to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer was right
+    Correct,
+    /// The answer was wrong, and too high
+    TooHigh,
+    /// The answer was wrong, and too low
+    TooLow,
+    /// The answer was wrong
+    WrongAnswer,
+    /// An answer was submitted too recently, so wait this long and try again
+    RateLimited(Duration),
+    /// The part was solved before
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Works out the verdict from the text of the response page.
+    pub fn from_html(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if html.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(parse_wait(html).unwrap_or_default()))
+        } else if html.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if html.contains("That's not the right answer") {
+            Some(Verdict::WrongAnswer)
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    fn to_record(&self) -> String {
+        match self {
+            Verdict::Correct => "correct".to_string(),
+            Verdict::TooHigh => "too-high".to_string(),
+            Verdict::TooLow => "too-low".to_string(),
+            Verdict::WrongAnswer => "wrong".to_string(),
+            Verdict::RateLimited(wait) => format!("rate-limited:{}", wait.as_secs()),
+            Verdict::AlreadySolved => "already-solved".to_string(),
+        }
+    }
+
+    fn from_record(s: &str) -> Option<Self> {
+        match s {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::WrongAnswer),
+            "already-solved" => Some(Verdict::AlreadySolved),
+            s => s
+                .strip_prefix("rate-limited:")
+                .and_then(|secs| str::parse::<u64>(secs).ok())
+                .map(|secs| Verdict::RateLimited(Duration::from_secs(secs))),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "Correct!"),
+            Verdict::TooHigh => write!(f, "Wrong, the answer is too high"),
+            Verdict::TooLow => write!(f, "Wrong, the answer is too low"),
+            Verdict::WrongAnswer => write!(f, "Wrong answer"),
+            Verdict::RateLimited(wait) => {
+                write!(f, "Rate limited, try again in {}s", wait.as_secs())
+            }
+            Verdict::AlreadySolved => write!(f, "This part has already been solved"),
+        }
+    }
+}
+
+/// Pulls the wait out of "You have 1m 5s left to wait".
+fn parse_wait(html: &str) -> Option<Duration> {
+    let (before, _) = html.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+    let mut secs = 0;
+    for unit in wait.split_ascii_whitespace() {
+        let (n, multiplier) = if let Some(n) = unit.strip_suffix('h') {
+            (n, 3600)
+        } else if let Some(n) = unit.strip_suffix('m') {
+            (n, 60)
+        } else {
+            (unit.strip_suffix('s')?, 1)
+        };
+        secs += str::parse::<u64>(n).ok()? * multiplier;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Every answer submitted so far, kept next to the inputs as tab separated
+/// `day`, `part`, `answer`, verdict and timestamp lines. Lines from before
+/// timestamps were kept are treated as long past.
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+struct Submission {
+    day: u8,
+    part: Part,
+    answer: Value,
+    verdict: Verdict,
+    /// When it was submitted, in seconds since the Unix epoch
+    timestamp: u64,
+}
+
+impl History {
+    /// Loads the history kept in `input_dir`, which is empty if nothing has
+    /// been submitted yet.
+    pub fn load(input_dir: &Path) -> Result<Self, Error> {
+        let path = input_dir.join("submissions.tsv");
+        let entries = tsv::load(&path, |fields| {
+            let day = str::parse::<u8>(fields.next()?).ok()?;
+            let part = Part::from_level(str::parse::<u8>(fields.next()?).ok()?)?;
+            let answer = str::parse::<Value>(fields.next()?).ok()?;
+            let verdict = Verdict::from_record(fields.next()?)?;
+            let timestamp = match fields.next() {
+                Some(timestamp) => str::parse::<u64>(timestamp).ok()?,
+                None => 0,
+            };
+            Some(Submission {
+                day,
+                part,
+                answer,
+                verdict,
+                timestamp,
+            })
+        })?;
+        Ok(History { path, entries })
+    }
+
+    /// Explains why `answer` shouldn't be submitted at `now`, in seconds since
+    /// the Unix epoch, if an earlier submission already tells us what the
+    /// website would say. The website's rate limit covers every puzzle, so a
+    /// wait it asked for on any of them has to pass first.
+    pub fn reason_to_skip(&self, day: u8, part: &Part, answer: &Value, now: u64) -> Option<String> {
+        let ready = self
+            .entries
+            .iter()
+            .filter_map(|submission| match submission.verdict {
+                Verdict::RateLimited(wait) => Some(submission.timestamp + wait.as_secs()),
+                _ => None,
+            })
+            .max();
+        if let Some(ready) = ready.filter(|&ready| ready > now) {
+            return Some(format!("rate limited, wait {}s", ready - now));
+        }
+
+        let previous = self
+            .entries
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == *part);
+        for Submission {
+            answer: previous_answer,
+            verdict,
+            ..
+        } in previous
+        {
+            match verdict {
+                Verdict::Correct | Verdict::AlreadySolved => {
+                    return Some(format!("already solved with {previous_answer}"))
+                }
+                Verdict::RateLimited(_) => continue,
+                verdict if previous_answer == answer => {
+                    return Some(format!("{answer} was already submitted: {verdict}"))
+                }
+                Verdict::TooHigh if at_least(answer, previous_answer) => {
+                    return Some(format!("{previous_answer} was already too high"))
+                }
+                Verdict::TooLow if at_least(previous_answer, answer) => {
+                    return Some(format!("{previous_answer} was already too low"))
+                }
+                _ => (),
+            }
+        }
+        None
+    }
+
+    /// Adds a submission made at `timestamp`, in seconds since the Unix epoch,
+    /// to the history file.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: Value,
+        verdict: Verdict,
+        timestamp: u64,
+    ) -> Result<(), Error> {
+        let line = format!(
+            "{day}\t{}\t{answer}\t{}\t{timestamp}\n",
+            part.level(),
+            verdict.to_record()
+        );
+        tsv::append(&self.path, &line)?;
+        self.entries.push(Submission {
+            day,
+            part,
+            answer,
+            verdict,
+            timestamp,
+        });
+        Ok(())
+    }
+}
+
+fn at_least(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => a >= b,
+        (Value::Big(a), Value::Big(b)) => a >= b,
+        (Value::Int(a), Value::Big(b)) => *a as i128 >= *b,
+        (Value::Big(a), Value::Int(b)) => *a >= *b as i128,
+        _ => false,
+    }
+}
+
+/// Submits `answer` unless the history already says how it would go, and
+/// records what the website said.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Value,
+) -> Result<Verdict, Error> {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    if let Some(reason) = history.reason_to_skip(day, &part, &answer, now) {
+        return Err(Error::Resubmission(reason));
+    }
+    let verdict = client.submit(year, day, &part, &answer)?;
+    history.record(day, part, answer, verdict.clone(), now)?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn classifies_responses() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Verdict::WrongAnswer,
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+        ];
+        for (message, verdict) in cases {
+            assert_eq!(Verdict::from_html(&page(message)), Some(verdict));
+        }
+    }
+
+    #[test]
+    fn classifies_rate_limits_with_their_wait() {
+        let html = page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 5s left to wait.",
+        );
+        assert_eq!(
+            Verdict::from_html(&html),
+            Some(Verdict::RateLimited(Duration::from_secs(65)))
+        );
+    }
+
+    #[test]
+    fn rate_limits_without_a_readable_wait_wait_for_nothing() {
+        let html = page("You gave an answer too recently.");
+        assert_eq!(
+            Verdict::from_html(&html),
+            Some(Verdict::RateLimited(Duration::ZERO))
+        );
+    }
+
+    #[test]
+    fn does_not_classify_other_pages() {
+        assert_eq!(Verdict::from_html(&page("Please log in.")), None);
+        assert_eq!(Verdict::from_html(""), None);
+    }
+
+    #[test]
+    fn parses_waits() {
+        let wait = |text: &str| parse_wait(text).map(|wait| wait.as_secs());
+        assert_eq!(wait("You have 32s left to wait."), Some(32));
+        assert_eq!(wait("You have 4m 0s left to wait."), Some(240));
+        assert_eq!(wait("You have 1h 2m 3s left to wait."), Some(3723));
+        assert_eq!(wait("You have 5m left to wait."), Some(300));
+        assert_eq!(wait("again.  You have 1m 5s left to wait."), Some(65));
+    }
+
+    #[test]
+    fn rejects_unreadable_waits() {
+        assert_eq!(parse_wait("You have a while left to wait."), None);
+        assert_eq!(parse_wait("You have 5 left to wait."), None);
+        assert_eq!(parse_wait("You have xs left to wait."), None);
+        assert_eq!(parse_wait("You have 32s to wait."), None);
+        assert_eq!(parse_wait("32s left to wait."), None);
+    }
+
+    #[test]
+    fn verdicts_survive_the_history_file() {
+        let verdicts = [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::WrongAnswer,
+            Verdict::RateLimited(Duration::from_secs(90)),
+            Verdict::AlreadySolved,
+        ];
+        for verdict in verdicts {
+            assert_eq!(Verdict::from_record(&verdict.to_record()), Some(verdict));
+        }
+    }
+
+    fn history(entries: Vec<(u8, Part, i64, Verdict, u64)>) -> History {
+        History {
+            path: PathBuf::new(),
+            entries: entries
+                .into_iter()
+                .map(|(day, part, answer, verdict, timestamp)| Submission {
+                    day,
+                    part,
+                    answer: Value::Int(answer),
+                    verdict,
+                    timestamp,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn skips_answers_the_history_already_rules_out() {
+        let history = history(vec![
+            (1, Part::One, 50, Verdict::TooHigh, 0),
+            (1, Part::One, 10, Verdict::TooLow, 0),
+            (1, Part::One, 30, Verdict::WrongAnswer, 0),
+            (2, Part::One, 7, Verdict::Correct, 0),
+        ]);
+        let skip = |day, answer| history.reason_to_skip(day, &Part::One, &Value::Int(answer), 0);
+        assert!(skip(1, 50).is_some());
+        assert!(skip(1, 60).is_some());
+        assert!(skip(1, 5).is_some());
+        assert!(skip(1, 30).is_some());
+        assert_eq!(skip(1, 20), None);
+        assert!(skip(2, 8).is_some());
+        assert_eq!(
+            history.reason_to_skip(1, &Part::Two, &Value::Int(50), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn waits_out_rate_limits_on_any_puzzle() {
+        let history = history(vec![(
+            3,
+            Part::Two,
+            9,
+            Verdict::RateLimited(Duration::from_secs(60)),
+            1000,
+        )]);
+        let skip = |now| history.reason_to_skip(1, &Part::One, &Value::Int(9), now);
+        assert_eq!(skip(1015), Some("rate limited, wait 45s".to_string()));
+        assert_eq!(skip(1060), None);
+    }
+}
@@ -1,16 +1,26 @@
+//! Day N
+
 #![allow(dead_code)]
 
-use crate::Part;
+use crate::{answer::Answer, error::ParseError, solver::Solver};
+
+/// The solver for day N.
+pub struct DayN;
+
+impl Solver for DayN {
+    const DAY: u8 = 0;
+
+    type Input = Vec<String>;
 
-pub fn run(lines: Vec<String>, part: Part) {
-    let before = std::time::Instant::now();
-    match part {
-        Part::One => part1(lines),
-        Part::Two => part2(lines),
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Ok(lines)
     }
-    println!("Elapsed: {:.2?}", before.elapsed());
-}
 
-fn part1(_lines: Vec<String>) {}
+    fn part_one(_lines: &Self::Input) -> Answer {
+        Answer::not_implemented()
+    }
 
-fn part2(_lines: Vec<String>) {}
+    fn part_two(_lines: &Self::Input) -> Answer {
+        Answer::not_implemented()
+    }
+}
@@ -0,0 +1,160 @@
+//! A history of benchmark timings, for spotting solutions that got slower.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime},
+};
+
+use crate::{bench::Benchmark, error::Error, tsv, Part};
+
+/// How many of the most recent runs make up a part's baseline.
+const BASELINE_RUNS: usize = 5;
+
+/// A single benchmark of a single part.
+pub struct Timing {
+    /// When it was benchmarked, in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// The commit that was benchmarked, if it was run from a git checkout
+    pub commit: Option<String>,
+    /// The day that was benchmarked
+    pub day: u8,
+    /// The part that was benchmarked
+    pub part: Part,
+    /// The median parse time plus the median solve time
+    pub median: Duration,
+}
+
+/// A part that was slower than its baseline by more than was allowed.
+pub struct Regression {
+    /// The day that got slower
+    pub day: u8,
+    /// The part that got slower
+    pub part: Part,
+    /// The part's usual time
+    pub baseline: Duration,
+    /// The time it took this run
+    pub median: Duration,
+}
+
+/// Every benchmark run so far, kept next to the inputs as tab separated
+/// timestamp, commit, `day`, `part` and median nanoseconds lines.
+pub struct Timings {
+    path: PathBuf,
+    entries: Vec<Timing>,
+}
+
+impl Timings {
+    /// Loads the timings kept in `input_dir`, which is empty if nothing has
+    /// been benchmarked yet.
+    pub fn load(input_dir: &Path) -> Result<Self, Error> {
+        let path = input_dir.join("timings.tsv");
+        let entries = tsv::load(&path, |fields| {
+            let timestamp = str::parse::<u64>(fields.next()?).ok()?;
+            let commit = match fields.next()? {
+                "-" => None,
+                commit => Some(commit.to_string()),
+            };
+            let day = str::parse::<u8>(fields.next()?).ok()?;
+            let part = Part::from_level(str::parse::<u8>(fields.next()?).ok()?)?;
+            let median = Duration::from_nanos(str::parse::<u64>(fields.next()?).ok()?);
+            Some(Timing {
+                timestamp,
+                commit,
+                day,
+                part,
+                median,
+            })
+        })?;
+        Ok(Timings { path, entries })
+    }
+
+    /// Every run of `part` of `day`, oldest first.
+    pub fn runs(&self, day: u8, part: &Part) -> Vec<&Timing> {
+        self.entries
+            .iter()
+            .filter(|timing| timing.day == day && timing.part == *part)
+            .collect()
+    }
+
+    /// The benchmarks that were slower than their part's baseline by more
+    /// than `max_slowdown` percent. Parts without any earlier runs can't
+    /// regress.
+    pub fn regressions(&self, benchmarks: &[Benchmark], max_slowdown: f64) -> Vec<Regression> {
+        benchmarks
+            .iter()
+            .filter_map(|benchmark| {
+                let median = median(benchmark)?;
+                let runs = self.runs(benchmark.day, &benchmark.part);
+                let baseline = baseline(&runs)?;
+                let allowed = baseline.mul_f64(1.0 + max_slowdown / 100.0);
+                (median > allowed).then(|| Regression {
+                    day: benchmark.day,
+                    part: benchmark.part.clone(),
+                    baseline,
+                    median,
+                })
+            })
+            .collect()
+    }
+
+    /// Adds every benchmark that finished to the timings file, along with the
+    /// time and the commit being benchmarked.
+    pub fn record(&mut self, benchmarks: &[Benchmark]) -> Result<(), Error> {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        let commit = commit();
+        let mut text = String::new();
+        for benchmark in benchmarks {
+            let Some(median) = median(benchmark) else {
+                continue;
+            };
+            text += &format!(
+                "{timestamp}\t{}\t{}\t{}\t{}\n",
+                commit.as_deref().unwrap_or("-"),
+                benchmark.day,
+                benchmark.part.level(),
+                median.as_nanos()
+            );
+            self.entries.push(Timing {
+                timestamp,
+                commit: commit.clone(),
+                day: benchmark.day,
+                part: benchmark.part.clone(),
+                median,
+            });
+        }
+        tsv::append(&self.path, &text)
+    }
+}
+
+/// A part's usual time: the median of its last few runs, so that one unlucky
+/// run doesn't move it much.
+pub fn baseline(runs: &[&Timing]) -> Option<Duration> {
+    let mut medians = runs[runs.len().saturating_sub(BASELINE_RUNS)..]
+        .iter()
+        .map(|timing| timing.median)
+        .collect::<Vec<Duration>>();
+    medians.sort();
+    medians.get(medians.len() / 2).copied()
+}
+
+fn median(benchmark: &Benchmark) -> Option<Duration> {
+    let (parse, solve) = benchmark.timings.as_ref().ok()?;
+    Some(parse.median + solve.median)
+}
+
+/// The commit of the crate being benchmarked, wherever it's run from, marked
+/// `-dirty` if there are uncommitted changes, or nothing if git or the crate's
+/// checkout isn't available.
+fn commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let commit = String::from_utf8(output.stdout).ok()?;
+    Some(commit.trim().to_string()).filter(|commit| !commit.is_empty())
+}
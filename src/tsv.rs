@@ -0,0 +1,48 @@
+//! The tab separated files kept next to the inputs, such as the cache of
+//! answers and the history of submissions.
+
+use std::{
+    fs::OpenOptions,
+    io::{self, Write},
+    path::Path,
+    str::Split,
+};
+
+use crate::error::Error;
+
+/// Reads each line of the file at `path` with `parse`, which is given the
+/// line's fields. Lines it can't make sense of are skipped, and a file that
+/// doesn't exist yet has no lines.
+pub(crate) fn load<T>(
+    path: &Path,
+    mut parse: impl FnMut(&mut Split<'_, char>) -> Option<T>,
+) -> Result<Vec<T>, Error> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(Error::Input(path.display().to_string(), e)),
+    };
+    Ok(text
+        .lines()
+        .filter_map(|line| parse(&mut line.split('\t')))
+        .collect())
+}
+
+/// Replaces the file at `path` with `lines`.
+pub(crate) fn save(path: &Path, lines: &str) -> Result<(), Error> {
+    write(path, lines, OpenOptions::new().write(true).truncate(true))
+}
+
+/// Adds `lines` to the end of the file at `path`.
+pub(crate) fn append(path: &Path, lines: &str) -> Result<(), Error> {
+    write(path, lines, OpenOptions::new().append(true))
+}
+
+/// Writes to the file at `path`, creating it and its directory if need be.
+fn write(path: &Path, lines: &str, options: &mut OpenOptions) -> Result<(), Error> {
+    path.parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| options.create(true).open(path))
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| Error::Write(path.display().to_string(), e))
+}
@@ -0,0 +1,58 @@
+//! Checking that inputs meet the assumptions each day's solutions make.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{
+    error::Error,
+    input::InputSource,
+    runner::{self, DaySelection},
+    solver::{CheckedAssumption, Registry},
+};
+
+/// The assumptions checked for a single day's input.
+pub struct Validation {
+    /// The day whose input was checked
+    pub day: u8,
+    /// Each assumption's description and whether it held, or why the input
+    /// couldn't be checked at all
+    pub results: Result<Vec<CheckedAssumption>, Error>,
+}
+
+impl Validation {
+    /// How many of the day's assumptions didn't hold, counting an input that
+    /// couldn't be checked as one.
+    pub fn failures(&self) -> usize {
+        match &self.results {
+            Ok(results) => results.iter().filter(|(_, result)| result.is_err()).count(),
+            Err(Error::NotImplemented(_)) => 0,
+            Err(_) => 1,
+        }
+    }
+}
+
+/// Parses each selected day's input and checks it against the day's
+/// assumptions.
+pub fn validate_selection(
+    registry: &Registry,
+    days: &DaySelection,
+    source: &InputSource,
+) -> Vec<Validation> {
+    days.days()
+        .iter()
+        .map(|&day| Validation {
+            day,
+            results: validate_day(registry, day, source),
+        })
+        .collect()
+}
+
+fn validate_day(
+    registry: &Registry,
+    day: u8,
+    source: &InputSource,
+) -> Result<Vec<CheckedAssumption>, Error> {
+    let solver = registry.get(day)?;
+    let input = solver.parse(source.open(day)?)?;
+    panic::catch_unwind(AssertUnwindSafe(|| solver.validate(input.as_ref())))
+        .map_err(|payload| Error::Panicked(runner::panic_message(payload.as_ref())))
+}
@@ -0,0 +1,214 @@
+//! Noticing when files change, so a day can be re-run as it's being solved.
+
+use std::{
+    env,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answers, encryption,
+    error::Error,
+    examples,
+    input::InputSource,
+    report,
+    runner::{self, DaySelection, PartSelection},
+    solver::Registry,
+};
+
+/// How long to wait after a change before reporting it, so that an editor
+/// writing a file in several steps only counts as one change.
+const SETTLE: Duration = Duration::from_millis(50);
+
+/// Watches files by polling their modification times, which needs no support
+/// from the platform and is plenty for a handful of files.
+pub struct Watcher {
+    interval: Duration,
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    /// Watches `paths`, looking for changes every `interval`. Files that don't
+    /// exist yet are watched for being created.
+    pub fn new(paths: Vec<PathBuf>, interval: Duration) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+        Watcher { interval, files }
+    }
+
+    /// Blocks until at least one file has been created, changed or removed,
+    /// returning the ones that were.
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(self.interval);
+            if self
+                .files
+                .iter()
+                .any(|(path, seen)| modified(path) != *seen)
+            {
+                thread::sleep(SETTLE);
+                return self
+                    .files
+                    .iter_mut()
+                    .filter_map(|(path, seen)| {
+                        let modified = modified(path);
+                        (modified != *seen).then(|| {
+                            *seen = modified;
+                            path.clone()
+                        })
+                    })
+                    .collect();
+            }
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// A day to keep re-running, and how.
+pub struct Watch {
+    /// The year the day is from
+    pub year: u16,
+    /// The day to run
+    pub day: u8,
+    /// Which of its parts to run
+    pub parts: PartSelection,
+    /// Whether to run the day's examples instead of its real input
+    pub test: bool,
+    /// The crate to rebuild and restart from when the day's source changes,
+    /// if it should be
+    pub rebuild: Option<PathBuf>,
+    /// How long to give each part before giving up on it
+    pub timeout: Option<Duration>,
+    /// How often to look for changes
+    pub interval: Duration,
+    /// The file of known-correct answers to compare with
+    pub answers: PathBuf,
+}
+
+/// Runs the day, then runs it again whenever its input, examples or answers
+/// change, until interrupted. If the day's source changes too, the crate is
+/// rebuilt and this process replaced by the new build.
+pub fn watch(
+    registry: &Registry,
+    watch: &Watch,
+    input_dir: &Path,
+    source: &InputSource,
+) -> Result<(), Error> {
+    let Watch { year, day, .. } = *watch;
+    registry.get(day)?;
+    let source_path = watch.rebuild.as_ref().map(|root| {
+        root.join("src")
+            .join(format!("year{year}"))
+            .join(format!("day{day}.rs"))
+    });
+    let input_path = input_dir.join(format!("day{day}.txt"));
+    let mut paths = vec![
+        encryption::encrypted_path(&input_path),
+        input_path,
+        examples::path(input_dir, day),
+        watch.answers.clone(),
+    ];
+    paths.extend(source_path.clone());
+    let mut watcher = Watcher::new(paths, watch.interval);
+
+    let days = DaySelection::single(day);
+    loop {
+        // Clear the screen and move the cursor back to the top
+        print!("\x1b[2J\x1b[H");
+        println!("Watching day {day} of {year}, press Ctrl-C to stop\n");
+        let checks = if watch.test {
+            examples::run_examples(registry, &days, &watch.parts, input_dir, watch.timeout, 1)
+        } else {
+            let outcomes = runner::run_selection(
+                registry,
+                &days,
+                &watch.parts,
+                source,
+                watch.timeout,
+                1,
+                None,
+            );
+            answers::compare(outcomes, year, source, &watch.answers)
+        };
+        match checks {
+            Ok(checks) => {
+                report::print_checks(&checks);
+                let elapsed = checks
+                    .iter()
+                    .map(|(outcome, _, _)| outcome.elapsed)
+                    .sum::<Duration>();
+                println!("\nElapsed: {elapsed:.2?}");
+            }
+            Err(e) => println!("error: {e}"),
+        }
+        io::stdout()
+            .flush()
+            .map_err(|e| Error::Write("stdout".to_string(), e))?;
+
+        let changed = watcher.wait();
+        if let (Some(root), Some(source_path)) = (&watch.rebuild, &source_path) {
+            if changed.contains(source_path) {
+                rebuild_and_restart(root)?;
+            }
+        }
+    }
+}
+
+/// Rebuilds the crate at `root` with the same profile and features as this
+/// build, then replaces this process with the new binary and the same
+/// arguments. Carries on with this one if the build fails.
+fn rebuild_and_restart(root: &Path) -> Result<(), Error> {
+    // Found before building, as the path can stop resolving once the binary
+    // has been replaced
+    let exe = env::current_exe().map_err(Error::Rebuild)?;
+    let mut build = Command::new("cargo");
+    build
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(root.join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    if !FEATURES.is_empty() {
+        build.arg("--features").arg(FEATURES.join(","));
+    }
+    if !build.status().map_err(Error::Rebuild)?.success() {
+        println!("\nBuild failed, still running the previous build");
+        return Ok(());
+    }
+    restart(Command::new(exe).args(env::args_os().skip(1)))
+}
+
+/// The cargo features this binary was built with.
+const FEATURES: &[&str] = &[
+    #[cfg(feature = "count-allocations")]
+    "count-allocations",
+];
+
+#[cfg(unix)]
+fn restart(command: &mut Command) -> Result<(), Error> {
+    use std::os::unix::process::CommandExt;
+
+    // Only returns if the new binary couldn't be started
+    Err(Error::Rebuild(command.exec()))
+}
+
+/// Without `exec`, the new binary runs as a child that this process waits on.
+#[cfg(not(unix))]
+fn restart(command: &mut Command) -> Result<(), Error> {
+    let status = command.status().map_err(Error::Rebuild)?;
+    std::process::exit(status.code().unwrap_or(1));
+}
@@ -0,0 +1,32 @@
+//! Solutions to the 2023 puzzles.
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::solver::Registry;
+
+/// Every implemented day of 2023.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register::<day1::Day1>();
+    registry.register::<day2::Day2>();
+    registry.register::<day3::Day3>();
+    registry.register::<day4::Day4>();
+    registry.register::<day5::Day5>();
+    registry.register::<day6::Day6>();
+    registry.register::<day7::Day7>();
+    registry.register::<day8::Day8>();
+    registry.register::<day9::Day9>();
+    registry.register::<day10::Day10>();
+    registry.register::<day11::Day11>();
+    registry
+}
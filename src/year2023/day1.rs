@@ -0,0 +1,185 @@
+//! Day 1: Trebuchet?!
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    input::RawInput,
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 1.
+pub struct Day1;
+
+impl Solver for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Calibration>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Self::parse_raw(&mut RawInput::from_lines(lines, "<lines>"))
+    }
+
+    fn parse_raw(input: &mut RawInput) -> Result<Self::Input, ParseError> {
+        input.parse_lines(|line| Ok(calibrate(line.text())))
+    }
+
+    fn part_one(calibrations: &Self::Input) -> Answer {
+        part1(calibrations)
+    }
+
+    fn part_two(calibrations: &Self::Input) -> Answer {
+        part2(calibrations)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Makes up `size` lines of letters, digits and spelled out digits, each with
+/// at least one real digit so that part one has something to find.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            let pieces = rng.range(1..=5);
+            let digit = rng.below(pieces);
+            for piece in 0..pieces {
+                if piece == digit {
+                    line.push(char::from(b'1' + rng.below(9) as u8));
+                }
+                match rng.below(3) {
+                    0 => line.push(char::from(b'1' + rng.below(9) as u8)),
+                    1 => line.push_str(DIGIT_WORDS[rng.below(9) as usize]),
+                    _ => (0..rng.range(1..=6))
+                        .for_each(|_| line.push(char::from(b'a' + rng.below(26) as u8))),
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    lines
+        .iter()
+        .map(|line| {
+            reference_calibration_value(line.chars().filter_map(|c| c.to_digit(10)).collect())
+        })
+        .sum::<u32>()
+        .into()
+}
+
+/// Checks each position in turn for a digit, or a digit's name, starting there.
+fn reference_part2(lines: &[String]) -> Value {
+    lines
+        .iter()
+        .map(|line| {
+            let digits = (0..line.len())
+                .filter_map(|idx| line.get(idx..))
+                .filter_map(|rest| {
+                    rest.chars()
+                        .next()
+                        .and_then(|c| c.to_digit(10))
+                        .or_else(|| {
+                            DIGIT_WORDS
+                                .iter()
+                                .position(|word| rest.starts_with(word))
+                                .map(|idx| idx as u32 + 1)
+                        })
+                })
+                .collect();
+            reference_calibration_value(digits)
+        })
+        .sum::<u32>()
+        .into()
+}
+
+fn reference_calibration_value(digits: Vec<u32>) -> u32 {
+    match (digits.first(), digits.last()) {
+        (Some(first), Some(last)) => first * 10 + last,
+        _ => 0,
+    }
+}
+
+/// A line's calibration value, read both ways.
+pub struct Calibration {
+    /// The value from the line's digits
+    pub digits: u32,
+    /// The value from the line's digits and spelled out digits
+    pub spelled: u32,
+}
+
+fn calibrate(line: &str) -> Calibration {
+    Calibration {
+        digits: parse_digit_line(line),
+        spelled: parse_spelled_line(line),
+    }
+}
+
+fn part1(calibrations: &[Calibration]) -> Answer {
+    let calibration_sum: u32 = calibrations
+        .iter()
+        .map(|calibration| calibration.digits)
+        .sum();
+    Answer::new("Calibration sum", calibration_sum)
+}
+
+fn parse_digit_line(line: &str) -> u32 {
+    let tens: u32 = {
+        let mut r = 0;
+        for c in line.chars() {
+            if let Some(n) = c.to_digit(10) {
+                r = n * 10;
+                break;
+            }
+        }
+        r
+    };
+    let ones: u32 = {
+        let mut r = 0;
+        for c in line.chars().rev() {
+            if let Some(n) = c.to_digit(10) {
+                r = n;
+                break;
+            }
+        }
+        r
+    };
+    tens + ones
+}
+
+fn part2(calibrations: &[Calibration]) -> Answer {
+    let calibration_sum: u32 = calibrations
+        .iter()
+        .map(|calibration| calibration.spelled)
+        .sum();
+    Answer::new("Calibration sum", calibration_sum)
+}
+
+/// The calibration value of a line whose digits may also be spelled out.
+/// Spelled out digits can overlap, as in "eightwo", so the first digit is
+/// looked for from the front and the last from the back.
+fn parse_spelled_line(line: &str) -> u32 {
+    let digit_at = |i: usize| {
+        let rest = &line.as_bytes()[i..];
+        match rest[0] {
+            b @ b'0'..=b'9' => Some(u32::from(b - b'0')),
+            _ => DIGIT_WORDS
+                .iter()
+                .position(|word| rest.starts_with(word.as_bytes()))
+                .map(|n| n as u32 + 1),
+        }
+    };
+    let tens = (0..line.len()).find_map(digit_at).unwrap_or(0);
+    let ones = (0..line.len()).rev().find_map(digit_at).unwrap_or(0);
+    tens * 10 + ones
+}
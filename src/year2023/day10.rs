@@ -0,0 +1,571 @@
+//! Day 10: Pipe Maze
+
+use std::{collections::BTreeSet, fmt};
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    parse,
+    rng::Rng,
+    solver::{Assumption, Generator, Reference, Solver},
+};
+
+/// The solver for day 10.
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        build_map(&lines)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        part1(map)
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        part2(map)
+    }
+
+    const ASSUMPTIONS: &'static [Assumption<Self::Input>] = &[
+        Assumption {
+            description: "there is exactly one start tile",
+            check: |map| has_one_start(map),
+        },
+        Assumption {
+            description: "exactly two pipes connect to the start",
+            check: |map| start_has_two_pipes(map),
+        },
+    ];
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+type Tile = (usize, usize);
+
+/// Makes up a `size` by `size` map with one loop through it, starting anywhere
+/// along it, and junk pipes everywhere else. The loop follows the outline of a
+/// random tree of blocks, the way you'd follow a maze's wall, so it never
+/// crosses itself.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let blocks = (size / 4).max(2);
+    let neighbours = |(row, col): Tile| {
+        [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ]
+        .into_iter()
+        .filter(|&(row, col)| row < blocks && col < blocks)
+    };
+
+    // Grow the tree over anywhere from a third of the blocks to all of them
+    let root = (
+        rng.below(blocks as u64) as usize,
+        rng.below(blocks as u64) as usize,
+    );
+    let mut tree = BTreeSet::from([root]);
+    let mut branches = Vec::new();
+    let mut frontier = neighbours(root)
+        .map(|next| (root, next))
+        .collect::<Vec<_>>();
+    let target = rng.range((blocks * blocks / 3) as u64..=(blocks * blocks) as u64) as usize;
+    while tree.len() < target && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.below(frontier.len() as u64) as usize);
+        if tree.insert(to) {
+            branches.push((from.min(to), from.max(to)));
+            frontier.extend(neighbours(to).map(|next| (to, next)));
+        }
+    }
+
+    // Each block starts as its own little loop, and each branch joins two loops
+    // into one by swapping the pipes along the edges they share
+    let mut links = BTreeSet::new();
+    let mut toggle = |a: Tile, b: Tile| {
+        if !links.insert((a.min(b), a.max(b))) {
+            links.remove(&(a.min(b), a.max(b)));
+        }
+    };
+    for &(row, col) in &tree {
+        let (top, left) = (row * 2, col * 2);
+        toggle((top, left), (top, left + 1));
+        toggle((top + 1, left), (top + 1, left + 1));
+        toggle((top, left), (top + 1, left));
+        toggle((top, left + 1), (top + 1, left + 1));
+    }
+    for ((row, col), (next_row, next_col)) in branches {
+        let (top, left) = (row * 2, col * 2);
+        let (next_top, next_left) = (next_row * 2, next_col * 2);
+        let corners = if next_row == row {
+            [(0, 1), (1, 1), (0, 0), (1, 0)]
+        } else {
+            [(1, 0), (1, 1), (0, 0), (0, 1)]
+        };
+        let [a, b, c, d] = corners;
+        let here = |(r, c): Tile| (top + r, left + c);
+        let there = |(r, c): Tile| (next_top + r, next_left + c);
+        toggle(here(a), here(b));
+        toggle(there(c), there(d));
+        toggle(here(a), there(c));
+        toggle(here(b), there(d));
+    }
+
+    // Spread the loop out over every other tile, leaving room inside it
+    let links = links
+        .into_iter()
+        .flat_map(|(a, b): (Tile, Tile)| {
+            let middle = (a.0 + b.0, a.1 + b.1);
+            [((a.0 * 2, a.1 * 2), middle), (middle, (b.0 * 2, b.1 * 2))]
+        })
+        .collect::<BTreeSet<(Tile, Tile)>>();
+    let tiles = links
+        .iter()
+        .flat_map(|&(a, b)| [a, b])
+        .collect::<BTreeSet<Tile>>();
+
+    let width = blocks * 4;
+    let mut map = (0..width)
+        .map(|row| {
+            (0..width)
+                .map(|col| {
+                    let linked = |other: Tile| {
+                        let tile = (row, col);
+                        links.contains(&(tile.min(other), tile.max(other)))
+                    };
+                    let north = row > 0 && linked((row - 1, col));
+                    let west = col > 0 && linked((row, col - 1));
+                    match (north, linked((row + 1, col)), linked((row, col + 1)), west) {
+                        (true, true, _, _) => '|',
+                        (_, _, true, true) => '-',
+                        (true, _, true, _) => 'L',
+                        (true, _, _, true) => 'J',
+                        (_, true, _, true) => '7',
+                        (_, true, true, _) => 'F',
+                        _ if rng.chance(1, 2) => '.',
+                        _ => *rng.choose(&['|', '-', 'L', 'J', '7', 'F']),
+                    }
+                })
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    // Clear any junk around the start, so only the loop connects to it
+    let (row, col) = *rng.choose(&tiles.iter().copied().collect::<Vec<Tile>>());
+    map[row][col] = 'S';
+    for (r, c) in [
+        (row.wrapping_sub(1), col),
+        (row + 1, col),
+        (row, col.wrapping_sub(1)),
+        (row, col + 1),
+    ] {
+        if r < width && c < width && !tiles.contains(&(r, c)) {
+            map[r][c] = '.';
+        }
+    }
+    map.into_iter().map(String::from_iter).collect()
+}
+
+fn has_one_start(map: &[Vec<char>]) -> Result<(), String> {
+    match map.iter().flatten().filter(|c| **c == 'S').count() {
+        1 => Ok(()),
+        n => Err(format!("expected 1 start tile but found {n}")),
+    }
+}
+
+fn start_has_two_pipes(map: &[Vec<char>]) -> Result<(), String> {
+    if !map.iter().flatten().any(|c| *c == 'S') {
+        return Err("there is no start tile".to_string());
+    }
+    match get_adjacent_pipes(&find_start(map), map).len() {
+        2 => Ok(()),
+        n => Err(format!("expected 2 connecting pipes but found {n}")),
+    }
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    (reference_loop(lines).1.len() / 2).into()
+}
+
+/// Casts a ray along each row, counting the tiles it reaches after crossing
+/// the loop an odd number of times. Only pipes heading north count as
+/// crossings, so that running along a stretch of the loop is a crossing if and
+/// only if it leaves on the opposite side to the one it joined from.
+fn reference_part2(lines: &[String]) -> Value {
+    let (map, tiles) = reference_loop(lines);
+    let mut inside = 0;
+    for (row, line) in map.iter().enumerate() {
+        let mut crossings = 0;
+        for (col, pipe) in line.iter().enumerate() {
+            if !tiles.contains(&(row, col)) {
+                inside += crossings % 2;
+            } else if reference_exits(*pipe).contains(&(-1, 0)) {
+                crossings += 1;
+            }
+        }
+    }
+    inside.into()
+}
+
+/// The map with the start replaced by the pipe that fits there, and every tile
+/// along the loop, found by walking it from the start.
+fn reference_loop(lines: &[String]) -> (Vec<Vec<char>>, BTreeSet<(usize, usize)>) {
+    let mut map = lines
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect::<Vec<Vec<char>>>();
+    let start = (0..map.len())
+        .flat_map(|row| (0..map[row].len()).map(move |col| (row, col)))
+        .find(|&(row, col)| map[row][col] == 'S')
+        .unwrap();
+    let step = |(row, col): (usize, usize), (down, right): (isize, isize)| {
+        Some((
+            row.checked_add_signed(down)?,
+            col.checked_add_signed(right)?,
+        ))
+    };
+    let connects = |map: &[Vec<char>], exit: (isize, isize)| {
+        step(start, exit)
+            .and_then(|(row, col)| map.get(row)?.get(col))
+            .is_some_and(|pipe| reference_exits(*pipe).contains(&(-exit.0, -exit.1)))
+    };
+    map[start.0][start.1] = "|-LJ7F"
+        .chars()
+        .find(|pipe| {
+            reference_exits(*pipe)
+                .iter()
+                .all(|exit| connects(&map, *exit))
+        })
+        .unwrap();
+
+    let mut tiles = BTreeSet::from([start]);
+    let (mut previous, mut tile) = (
+        start,
+        step(start, reference_exits(map[start.0][start.1])[0]).unwrap(),
+    );
+    while tile != start {
+        tiles.insert(tile);
+        let next = reference_exits(map[tile.0][tile.1])
+            .iter()
+            .map(|exit| step(tile, *exit).unwrap())
+            .find(|next| *next != previous)
+            .unwrap();
+        (previous, tile) = (tile, next);
+    }
+    (map, tiles)
+}
+
+/// The rows and columns a pipe leads to from its tile.
+fn reference_exits(pipe: char) -> &'static [(isize, isize)] {
+    match pipe {
+        '|' => &[(-1, 0), (1, 0)],
+        '-' => &[(0, -1), (0, 1)],
+        'L' => &[(-1, 0), (0, 1)],
+        'J' => &[(-1, 0), (0, -1)],
+        '7' => &[(1, 0), (0, -1)],
+        'F' => &[(1, 0), (0, 1)],
+        _ => &[],
+    }
+}
+
+fn part1(map: &[Vec<char>]) -> Answer {
+    let start = find_start(map);
+    let mut pipes = get_adjacent_pipes(&start, map);
+    // Assumes that only two directions will be valid
+    let (mut forward_dir, forward_pipe_type) = pipes.pop().unwrap();
+    let (mut backwards_dir, backwards_pipe_type) = pipes.pop().unwrap();
+
+    let forward_position = start
+        .position
+        .increment(&forward_dir)
+        .expect("to be valid position");
+    let mut forward_pipe = Pipe {
+        pipe_type: forward_pipe_type,
+        position: forward_position,
+    };
+
+    let backwards_position = start
+        .position
+        .increment(&backwards_dir)
+        .expect("to be valid position");
+    let mut backwards_pipe = Pipe {
+        pipe_type: backwards_pipe_type,
+        position: backwards_position,
+    };
+
+    // 3: Furthest point is when forwads and backwards meet again
+    let mut distance = 1;
+    while forward_pipe.position != backwards_pipe.position {
+        forward_dir = forward_pipe.move_through(&forward_dir);
+        forward_pipe.position = forward_pipe
+            .position
+            .increment(&forward_dir)
+            .expect("to be valid position");
+        forward_pipe.pipe_type =
+            PipeType::from_map(map, &forward_pipe.position).expect("to be valid pipe");
+
+        backwards_dir = backwards_pipe.move_through(&backwards_dir);
+        backwards_pipe.position = backwards_pipe
+            .position
+            .increment(&backwards_dir)
+            .expect("to be valid position");
+        backwards_pipe.pipe_type =
+            PipeType::from_map(map, &backwards_pipe.position).expect("to be valid pipe");
+
+        distance += 1;
+    }
+
+    Answer::new("Furthest point from start", distance)
+}
+
+fn try_get_pipe_type(pipe: &Pipe, direction: &Direction, map: &[Vec<char>]) -> Option<PipeType> {
+    if let Some(position) = &pipe.position.increment(direction) {
+        PipeType::from_map(map, position)
+    } else {
+        None
+    }
+}
+
+fn part2(map: &[Vec<char>]) -> Answer {
+    // Calculate interior points with Pick's theorem
+    // loop_area = interior_points + (boundary_points / 2) - 1
+    // rewriting, we get
+    // interior_points = loop_area - (boundary_points / 2) - 1
+    // We get loop_area with the Shoelace formula
+    // border_points = (x1, y1), (x2, y2), (x3, y3), ...
+    // 2 * loop_area = x1 * y2 - y1 * x2 + x2 * y3 - y2 * x3 + ...
+    // loop_area = (result..) / 2
+    let start = find_start(map);
+    let mut adjacent_pipes = get_adjacent_pipes(&start, map);
+    adjacent_pipes.pop();
+    let (mut cursor_dir, cursor_type) = adjacent_pipes.pop().unwrap();
+
+    let cursor_position = start
+        .position
+        .increment(&cursor_dir)
+        .expect("to be valid position");
+    let mut cursor_pipe = Pipe {
+        pipe_type: cursor_type,
+        position: cursor_position,
+    };
+
+    let mut border_points = vec![start.position.clone()];
+    let mut vertices = vec![start.position.clone()];
+
+    while cursor_pipe.position != start.position {
+        border_points.push(cursor_pipe.position.clone());
+        match cursor_pipe.pipe_type {
+            PipeType::TurnF | PipeType::Turn7 | PipeType::TurnJ | PipeType::TurnL => {
+                vertices.push(cursor_pipe.position.clone());
+            }
+            _ => (),
+        }
+
+        cursor_dir = cursor_pipe.move_through(&cursor_dir);
+        cursor_pipe.position = cursor_pipe
+            .position
+            .increment(&cursor_dir)
+            .expect("to be valid position");
+        cursor_pipe.pipe_type =
+            PipeType::from_map(map, &cursor_pipe.position).expect("to be valid pipe");
+    }
+
+    let shoelace = vertices
+        .windows(2)
+        .map(|pair| {
+            let (x1, y1) = (pair[0].col as i32, pair[0].row as i32);
+            let (x2, y2) = (pair[1].col as i32, pair[1].row as i32);
+            x1 * y2 - x2 * y1
+        })
+        .sum::<i32>();
+    let last = vertices.last().unwrap();
+    let first = vertices.first().unwrap();
+    let shoelace = shoelace + ((last.col * first.row) as i32 - (last.row * first.col) as i32);
+
+    let loop_area = i32::abs(shoelace) / 2;
+    let interior_points = loop_area - (border_points.len() as i32 / 2) + 1;
+    Answer::new("Tiles inside the loop", interior_points)
+}
+
+fn build_map(lines: &[String]) -> Result<Vec<Vec<char>>, ParseError> {
+    parse::lines(lines)
+        .map(|line| {
+            match line
+                .text()
+                .char_indices()
+                .find(|(_, c)| !"|-FJ7LS.".contains(*c))
+            {
+                Some((idx, c)) => Err(line.error(
+                    &line.text()[idx..idx + c.len_utf8()],
+                    format!("unknown tile '{c}'"),
+                )),
+                None => Ok(line.text().chars().collect()),
+            }
+        })
+        .collect()
+}
+
+fn find_start(map: &[Vec<char>]) -> Pipe {
+    let mut s = None;
+
+    for (row, line) in map.iter().enumerate() {
+        if s.is_some() {
+            break;
+        }
+        for (col, c) in line.iter().enumerate() {
+            if *c == 'S' {
+                s = Some(Position::new(row, col));
+                break;
+            }
+        }
+    }
+
+    Pipe::new(PipeType::TurnF, s.unwrap())
+}
+
+fn get_adjacent_pipes(source: &Pipe, map: &[Vec<char>]) -> Vec<(Direction, PipeType)> {
+    [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ]
+    .into_iter()
+    .map(|direction| {
+        (
+            direction.clone(),
+            try_get_pipe_type(source, &direction, map),
+        )
+    })
+    .filter_map(|(direction, pipe_type)| match pipe_type {
+        // Only pipes that lead back to the start are part of the loop
+        Some(pipe_type) if pipe_type.can_enter_from(&direction) => Some((direction, pipe_type)),
+        _ => None,
+    })
+    .collect::<Vec<(Direction, PipeType)>>()
+}
+
+#[derive(Clone, PartialEq)]
+struct Position {
+    row: usize,
+    col: usize,
+}
+
+impl fmt::Debug for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.row, self.col)
+    }
+}
+
+impl Position {
+    fn new(row: usize, col: usize) -> Self {
+        Position { row, col }
+    }
+
+    fn increment(&self, direction: &Direction) -> Option<Position> {
+        match (direction, self.row, self.col) {
+            (Direction::North, 0, _col) => None,
+            (Direction::North, _row, _col) => Some(Position::new(self.row - 1, self.col)),
+            (Direction::South, row, col) => Some(Position::new(row + 1, col)),
+            (Direction::East, row, col) => Some(Position::new(row, col + 1)),
+            (Direction::West, _row, 0) => None,
+            (Direction::West, _row, _col) => Some(Position::new(self.row, self.col - 1)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug)]
+enum PipeType {
+    Vertical,
+    Horizontal,
+    TurnF,
+    Turn7,
+    TurnJ,
+    TurnL,
+    S,
+}
+
+impl PipeType {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Self::Vertical),
+            '-' => Some(Self::Horizontal),
+            'F' => Some(Self::TurnF),
+            '7' => Some(Self::Turn7),
+            'J' => Some(Self::TurnJ),
+            'L' => Some(Self::TurnL),
+            'S' => Some(Self::S),
+            _ => None,
+        }
+    }
+
+    fn from_map(map: &[Vec<char>], position: &Position) -> Option<Self> {
+        map.get(position.row)
+            .and_then(|row| row.get(position.col))
+            .and_then(|c| PipeType::from_char(*c))
+    }
+
+    /// Whether the pipe can be entered while heading in `direction`.
+    fn can_enter_from(&self, direction: &Direction) -> bool {
+        matches!(
+            (self, direction),
+            (PipeType::Vertical, Direction::North | Direction::South)
+                | (PipeType::Horizontal, Direction::East | Direction::West)
+                | (PipeType::TurnF, Direction::North | Direction::West)
+                | (PipeType::Turn7, Direction::North | Direction::East)
+                | (PipeType::TurnJ, Direction::East | Direction::South)
+                | (PipeType::TurnL, Direction::West | Direction::South)
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Pipe {
+    pipe_type: PipeType,
+    position: Position,
+}
+
+impl Pipe {
+    fn new(pipe_type: PipeType, position: Position) -> Self {
+        Pipe {
+            pipe_type,
+            position,
+        }
+    }
+
+    fn move_through(&self, incoming_direction: &Direction) -> Direction {
+        match (&self.pipe_type, incoming_direction) {
+            (PipeType::Vertical, Direction::North) => Direction::North,
+            (PipeType::Vertical, Direction::South) => Direction::South,
+            (PipeType::Horizontal, Direction::East) => Direction::East,
+            (PipeType::Horizontal, Direction::West) => Direction::West,
+            (PipeType::TurnF, Direction::North) => Direction::East,
+            (PipeType::TurnF, Direction::West) => Direction::South,
+            (PipeType::Turn7, Direction::North) => Direction::West,
+            (PipeType::Turn7, Direction::East) => Direction::South,
+            (PipeType::TurnJ, Direction::East) => Direction::North,
+            (PipeType::TurnJ, Direction::South) => Direction::West,
+            (PipeType::TurnL, Direction::West) => Direction::North,
+            (PipeType::TurnL, Direction::South) => Direction::East,
+            (pipe, incoming_direction) => {
+                panic!("Invalid move: enterting {pipe:?} from {incoming_direction:?}")
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Day 11: Cosmic Expansion
+
+use std::collections::HashSet;
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    parse,
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 11.
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        get_map(&lines)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        part1(map)
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        part2(map)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+/// Makes up a `size` by `size` image with a galaxy in about one in every
+/// forty places, leaving about one in ten rows and columns empty to be
+/// expanded.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut empty = || (0..size).map(|_| rng.chance(1, 10)).collect::<Vec<bool>>();
+    let (empty_rows, empty_cols) = (empty(), empty());
+    empty_rows
+        .iter()
+        .map(|empty_row| {
+            empty_cols
+                .iter()
+                .map(|empty_col| {
+                    if !empty_row && !empty_col && rng.chance(1, 40) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Actually expands the image, then measures between the galaxies in it.
+fn reference_part1(lines: &[String]) -> Value {
+    let mut image = Vec::new();
+    for line in lines {
+        image.push(line.clone());
+        if !line.contains('#') {
+            image.push(line.clone());
+        }
+    }
+    for col in (0..lines[0].len()).rev() {
+        if lines.iter().all(|line| line.as_bytes()[col] != b'#') {
+            for line in &mut image {
+                line.insert(col, '.');
+            }
+        }
+    }
+    let galaxies = reference_galaxies(&image);
+    let mut total = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            total += a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+        }
+    }
+    total.into()
+}
+
+/// Walks between each pair of galaxies a row and a column at a time, taking a
+/// million steps for each row or column without any galaxies.
+fn reference_part2(lines: &[String]) -> Value {
+    let galaxies = reference_galaxies(lines);
+    let empty_rows = lines
+        .iter()
+        .map(|line| !line.contains('#'))
+        .collect::<Vec<bool>>();
+    let empty_cols = (0..lines[0].len())
+        .map(|col| lines.iter().all(|line| line.as_bytes()[col] != b'#'))
+        .collect::<Vec<bool>>();
+    let steps = |empty: &[bool], from: usize, to: usize| {
+        (from.min(to)..from.max(to))
+            .map(|idx| if empty[idx] { 1_000_000 } else { 1 })
+            .sum::<usize>()
+    };
+    let mut total = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            total += steps(&empty_rows, a.0, b.0) + steps(&empty_cols, a.1, b.1);
+        }
+    }
+    total.into()
+}
+
+fn reference_galaxies(image: &[String]) -> Vec<(usize, usize)> {
+    image
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| line.match_indices('#').map(move |(col, _)| (row, col)))
+        .collect()
+}
+
+fn part1(map: &[Vec<char>]) -> Answer {
+    let galaxies: Vec<Galaxy> = get_galaxies(map);
+
+    let (rows_with_galaxies, cols_with_galaxies) = get_rows_and_cols_with_galaxies(&galaxies);
+
+    let mut total_distance = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            total_distance += true_distance(
+                &galaxies[i],
+                &galaxies[j],
+                &rows_with_galaxies,
+                &cols_with_galaxies,
+                1,
+            )
+        }
+    }
+    Answer::new(
+        "Total distance of shortest paths between all galaxies",
+        total_distance,
+    )
+}
+
+fn part2(map: &[Vec<char>]) -> Answer {
+    let galaxies: Vec<Galaxy> = get_galaxies(map);
+
+    let (rows_with_galaxies, cols_with_galaxies) = get_rows_and_cols_with_galaxies(&galaxies);
+
+    let mut total_distance = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            total_distance += true_distance(
+                &galaxies[i],
+                &galaxies[j],
+                &rows_with_galaxies,
+                &cols_with_galaxies,
+                999999,
+            )
+        }
+    }
+    Answer::new(
+        "Total distance of shortest paths between all galaxies",
+        total_distance,
+    )
+}
+
+/// Where a galaxy appears in the image.
+pub struct Galaxy {
+    /// The row, from the top
+    pub row: usize,
+    /// The column, from the left
+    pub col: usize,
+}
+
+impl Galaxy {
+    /// A galaxy at `row` and `col`.
+    pub fn new(row: usize, col: usize) -> Self {
+        Galaxy { row, col }
+    }
+
+    /// The distance to `other` before the universe expands.
+    pub fn distance(&self, other: &Galaxy) -> usize {
+        usize::abs_diff(self.row, other.row) + usize::abs_diff(self.col, other.col)
+    }
+}
+
+fn get_map(lines: &[String]) -> Result<Vec<Vec<char>>, ParseError> {
+    parse::lines(lines)
+        .map(
+            |line| match line.text().char_indices().find(|(_, c)| !"#.".contains(*c)) {
+                Some((idx, c)) => Err(line.error(
+                    &line.text()[idx..idx + c.len_utf8()],
+                    format!("unknown tile '{c}'"),
+                )),
+                None => Ok(line.text().chars().collect()),
+            },
+        )
+        .collect()
+}
+
+/// Every galaxy in the image, from top to bottom.
+pub fn get_galaxies(map: &[Vec<char>]) -> Vec<Galaxy> {
+    map.iter()
+        .enumerate()
+        .flat_map(|(row, chars)| {
+            chars
+                .iter()
+                .enumerate()
+                .filter_map(move |(col, c)| {
+                    if *c == '#' {
+                        Some(Galaxy::new(row, col))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<Galaxy>>()
+        })
+        .collect()
+}
+
+/// The rows and columns that have any galaxies, which don't expand.
+pub fn get_rows_and_cols_with_galaxies(galaxies: &[Galaxy]) -> (HashSet<usize>, HashSet<usize>) {
+    let rows_with_galaxies = galaxies.iter().map(|g| g.row).collect::<HashSet<usize>>();
+
+    let cols_with_galaxies = galaxies.iter().map(|g| g.col).collect::<HashSet<usize>>();
+
+    (rows_with_galaxies, cols_with_galaxies)
+}
+
+/// The distance between two galaxies once every empty row and column
+/// between them has grown by `expansion_factor`.
+pub fn true_distance(
+    this_galaxy: &Galaxy,
+    other_galaxy: &Galaxy,
+    rows_with_galaxies: &HashSet<usize>,
+    cols_with_galaxies: &HashSet<usize>,
+    expansion_factor: usize,
+) -> usize {
+    let mut total_distance = this_galaxy.distance(other_galaxy);
+
+    for row in
+        usize::min(this_galaxy.row, other_galaxy.row)..usize::max(this_galaxy.row, other_galaxy.row)
+    {
+        if !rows_with_galaxies.contains(&row) {
+            total_distance += expansion_factor;
+        }
+    }
+
+    for col in
+        usize::min(this_galaxy.col, other_galaxy.col)..usize::max(this_galaxy.col, other_galaxy.col)
+    {
+        if !cols_with_galaxies.contains(&col) {
+            total_distance += expansion_factor;
+        }
+    }
+
+    total_distance
+}
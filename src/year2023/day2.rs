@@ -0,0 +1,222 @@
+//! Day 2: Cube Conundrum
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    input::RawInput,
+    parse::Line,
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 2.
+pub struct Day2;
+
+impl Solver for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Self::parse_raw(&mut RawInput::from_lines(lines, "<lines>"))
+    }
+
+    fn parse_raw(input: &mut RawInput) -> Result<Self::Input, ParseError> {
+        let mut id = 0;
+        input.parse_lines(|line| {
+            id += 1;
+            parse_game(id, line)
+        })
+    }
+
+    fn part_one(games: &Self::Input) -> Answer {
+        part1(games)
+    }
+
+    fn part_two(games: &Self::Input) -> Answer {
+        part2(games)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+/// Makes up `size` games of up to six handfuls each.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (1..=size)
+        .map(|round| {
+            let draws = (0..rng.range(1..=6))
+                .map(|_| {
+                    let mut colors = vec!["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    colors.truncate(rng.range(1..=3) as usize);
+                    colors
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.range(1..=20)))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .collect::<Vec<String>>();
+            format!("Game {round}: {}", draws.join("; "))
+        })
+        .collect()
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    lines
+        .iter()
+        .map(|line| reference_most_cubes(line))
+        .filter(|(_, [red, green, blue])| *red <= 12 && *green <= 13 && *blue <= 14)
+        .map(|(id, _)| id)
+        .sum::<u32>()
+        .into()
+}
+
+fn reference_part2(lines: &[String]) -> Value {
+    lines
+        .iter()
+        .map(|line| reference_most_cubes(line))
+        .map(|(_, [red, green, blue])| red * green * blue)
+        .sum::<u32>()
+        .into()
+}
+
+/// The game's ID and the most red, green and blue cubes in any one handful.
+fn reference_most_cubes(line: &str) -> (u32, [u32; 3]) {
+    let (game, draws) = line.split_once(": ").unwrap();
+    let id = game.trim_start_matches("Game ").parse().unwrap();
+    let mut most = [0; 3];
+    for cubes in draws.split([';', ',']) {
+        let (count, color) = cubes.trim().split_once(' ').unwrap();
+        let color = ["red", "green", "blue"]
+            .iter()
+            .position(|c| *c == color)
+            .unwrap();
+        most[color] = most[color].max(count.parse().unwrap());
+    }
+    (id, most)
+}
+
+fn part1(games: &[Game]) -> Answer {
+    let (red_cubes, green_cubes, blue_cubes) = (12, 13, 14);
+    let sum_of_possible_game_ids: u32 = games
+        .iter()
+        .filter(|game| game.is_possible(red_cubes, green_cubes, blue_cubes))
+        .map(|game| game.round)
+        .sum();
+    Answer::new("Sum of possible game IDs", sum_of_possible_game_ids)
+}
+
+fn part2(games: &[Game]) -> Answer {
+    let sum_of_power: u32 = games
+        .iter()
+        .map(Game::minimum_cubes_needed)
+        .map(|cube_count| cube_count.0 * cube_count.1 * cube_count.2)
+        .sum();
+    Answer::new("Sum of power of minimum cubes needed", sum_of_power)
+}
+
+/// A game and every handful of cubes drawn during it.
+pub struct Game {
+    /// The game's ID
+    pub round: u32,
+    /// Each handful, in order
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    fn is_possible(&self, red_cubes: u32, green_cubes: u32, blue_cubes: u32) -> bool {
+        self.draws
+            .iter()
+            .all(|round| round.is_possible(red_cubes, green_cubes, blue_cubes))
+    }
+
+    fn minimum_cubes_needed(game: &Game) -> (u32, u32, u32) {
+        game.draws
+            .iter()
+            .map(Draw::minimum_cubes_needed)
+            .fold((0, 0, 0), |acc, draw| {
+                (
+                    u32::max(acc.0, draw.0),
+                    u32::max(acc.1, draw.1),
+                    u32::max(acc.2, draw.2),
+                )
+            })
+        /*
+            .fold(
+            (0, 0, 0),
+            |((prev_red, prev_green, prev_blue), (red, green, blue))| {
+                (
+                    u32::max(prev_red, red),
+                    u32::max(prev_green, green),
+                    u32::max(prev_blue, blue),
+                )
+            },
+        )
+            */
+    }
+}
+
+/// The cubes of each color in a single handful, if any were drawn.
+pub struct Draw {
+    /// Red cubes
+    pub red: Option<u32>,
+    /// Green cubes
+    pub green: Option<u32>,
+    /// Blue cubes
+    pub blue: Option<u32>,
+}
+
+impl Draw {
+    fn empty() -> Self {
+        Draw {
+            red: None,
+            green: None,
+            blue: None,
+        }
+    }
+
+    fn is_possible(&self, red_cubes: u32, green_cubes: u32, blue_cubes: u32) -> bool {
+        self.red.unwrap_or(0) <= red_cubes
+            && self.green.unwrap_or(0) <= green_cubes
+            && self.blue.unwrap_or(0) <= blue_cubes
+    }
+
+    fn minimum_cubes_needed(&self) -> (u32, u32, u32) {
+        (
+            self.red.unwrap_or(0),
+            self.green.unwrap_or(0),
+            self.blue.unwrap_or(0),
+        )
+    }
+}
+
+fn parse_game(round: u32, line: Line) -> Result<Game, ParseError> {
+    let draws = line
+        .split_once(line.text(), ':')?
+        .1
+        .split(';')
+        .map(str::trim)
+        .map(|draw| parse_draw(line, draw))
+        .collect::<Result<Vec<Draw>, ParseError>>()?;
+    Ok(Game { round, draws })
+}
+
+fn parse_draw<'a>(line: Line<'a>, draw_str: &'a str) -> Result<Draw, ParseError> {
+    let mut draw = Draw::empty();
+    for single_draw in draw_str.split(',') {
+        let (count_str, color_str) = line.split_once(single_draw.trim(), ' ')?;
+        let count = line.parse::<u32>(count_str)?;
+        match color_str {
+            "red" => draw.red = Some(count),
+            "green" => draw.green = Some(count),
+            "blue" => draw.blue = Some(count),
+            _ => return Err(line.error(color_str, format!("unknown color '{color_str}'"))),
+        }
+    }
+    Ok(draw)
+}
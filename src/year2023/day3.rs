@@ -0,0 +1,327 @@
+//! Day 3: Gear Ratios
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 3.
+pub struct Day3;
+
+impl Solver for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Ok(lines)
+    }
+
+    fn part_one(lines: &Self::Input) -> Answer {
+        part1(lines)
+    }
+
+    fn part_two(lines: &Self::Input) -> Answer {
+        part2(lines)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// Makes up a `size` by `size` schematic, scattering numbers of up to three
+/// digits and symbols with something other than a digit between each number.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            while line.len() < size {
+                let number = rng.range(1..=999).to_string();
+                if rng.chance(1, 4) && line.len() + number.len() <= size {
+                    line.push_str(&number);
+                }
+                if rng.chance(1, 10) {
+                    line.push(*rng.choose(&SYMBOLS));
+                } else {
+                    line.push('.');
+                }
+            }
+            line.truncate(size);
+            line
+        })
+        .collect()
+}
+
+/// Checks every tile of the schematic against every number for a symbol next
+/// to it.
+fn reference_part1(lines: &[String]) -> Value {
+    let schematic = reference_schematic(lines);
+    let numbers = reference_numbers(&schematic);
+    numbers
+        .iter()
+        .filter(|number| {
+            schematic.iter().enumerate().any(|(row, line)| {
+                line.iter()
+                    .enumerate()
+                    .any(|(col, c)| is_symbol(*c) && reference_adjacent(number, row, col))
+            })
+        })
+        .map(|(_, _, _, value)| value)
+        .sum::<u32>()
+        .into()
+}
+
+fn reference_part2(lines: &[String]) -> Value {
+    let schematic = reference_schematic(lines);
+    let numbers = reference_numbers(&schematic);
+    let mut sum = 0;
+    for (row, line) in schematic.iter().enumerate() {
+        for (col, c) in line.iter().enumerate() {
+            let adjacent = numbers
+                .iter()
+                .filter(|number| reference_adjacent(number, row, col))
+                .collect::<Vec<_>>();
+            if *c == '*' && adjacent.len() == 2 {
+                sum += adjacent[0].3 * adjacent[1].3;
+            }
+        }
+    }
+    sum.into()
+}
+
+fn reference_schematic(lines: &[String]) -> Vec<Vec<char>> {
+    lines.iter().map(|line| line.chars().collect()).collect()
+}
+
+/// Each number's row, the columns it starts and ends before, and its value.
+fn reference_numbers(schematic: &[Vec<char>]) -> Vec<(usize, usize, usize, u32)> {
+    let mut numbers = Vec::new();
+    for (row, line) in schematic.iter().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            let len = line[col..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if len > 0 {
+                let value = String::from_iter(&line[col..col + len]).parse().unwrap();
+                numbers.push((row, col, col + len, value));
+            }
+            col += len + 1;
+        }
+    }
+    numbers
+}
+
+fn reference_adjacent(
+    &(number_row, start, end, _): &(usize, usize, usize, u32),
+    row: usize,
+    col: usize,
+) -> bool {
+    row + 1 >= number_row && row <= number_row + 1 && col + 1 >= start && col <= end
+}
+
+fn part1(lines: &[String]) -> Answer {
+    // 1: Find all symbols with their coordinate
+    let symbol_positions = get_symbol_positions(lines);
+    // 2: Find all numbers and list all adjacent coordinates
+    let sum_of_part_numbers: usize = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| get_part_numbers(row, line))
+        .filter(|part_number| part_number.is_valid(&symbol_positions))
+        .map(|part_number| part_number.id)
+        .sum();
+    Answer::new("Sum of part numbers", sum_of_part_numbers)
+}
+
+fn part2(lines: &[String]) -> Answer {
+    let mut parts_adjacent_to_gears = HashMap::<Position, Vec<usize>>::new();
+    // Find all gear positions
+    let gear_positions = get_gear_positions(lines);
+
+    // Get all parts adjacent to gears, and build out map. A part can be next to
+    // more than one gear, and counts towards each of them
+    for part_number in lines
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| get_part_numbers(row, line))
+    {
+        for gear_position in part_number.adjacent_gear_positions(&gear_positions) {
+            parts_adjacent_to_gears
+                .entry(gear_position)
+                .or_default()
+                .push(part_number.id);
+        }
+    }
+
+    let sum_of_gear_ratios: usize = parts_adjacent_to_gears
+        .values()
+        .filter_map(|adjacent_parts| {
+            if adjacent_parts.len() == 2 {
+                Some(adjacent_parts[0] * adjacent_parts[1])
+            } else {
+                None
+            }
+        })
+        .sum();
+    Answer::new("Sum of gear ratios", sum_of_gear_ratios)
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Position {
+    row: usize,
+    col: usize,
+}
+
+impl Position {
+    fn new(row: usize, col: usize) -> Self {
+        Position { row, col }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+struct PartNumber {
+    id: usize,
+    position: Position,
+}
+
+impl PartNumber {
+    fn new(id: usize, position: Position) -> Self {
+        PartNumber { id, position }
+    }
+
+    fn is_valid(&self, symbol_positions: &HashSet<Position>) -> bool {
+        let adjacent_positions = self.get_adjacent_positions();
+        for position in adjacent_positions {
+            if symbol_positions.contains(&position) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn adjacent_gear_positions(&self, gear_positions: &HashSet<Position>) -> Vec<Position> {
+        self.get_adjacent_positions()
+            .into_iter()
+            .filter(|position| gear_positions.contains(position))
+            .collect()
+    }
+
+    fn get_adjacent_positions(&self) -> Vec<Position> {
+        let mut adjacent_positions = Vec::<Position>::new();
+
+        let len = self.id.to_string().len();
+        let curr_row = self.position.row;
+        let left_bound = if self.position.col != 0 {
+            self.position.col - 1
+        } else {
+            self.position.col
+        };
+        let right_bound = self.position.col + len;
+
+        // Check sides
+        let left = Position::new(self.position.row, left_bound);
+        let right = Position::new(self.position.row, right_bound);
+        adjacent_positions.push(left);
+        adjacent_positions.push(right);
+
+        // Check below
+        let mut rows_to_check = vec![curr_row + 1];
+        if curr_row != 0 {
+            // Check above
+            rows_to_check.push(curr_row - 1);
+        }
+
+        for row in rows_to_check {
+            for col in left_bound..=right_bound {
+                adjacent_positions.push(Position::new(row, col));
+            }
+        }
+        adjacent_positions
+    }
+}
+
+impl fmt::Display for PartNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Part {} at {}", self.id, self.position)
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_alphanumeric() && c != '.'
+}
+
+fn get_symbol_positions(lines: &[String]) -> HashSet<Position> {
+    let mut symbol_positions = HashSet::<Position>::new();
+    lines.iter().enumerate().for_each(|(row, line)| {
+        line.chars().enumerate().for_each(|(col, c)| {
+            if is_symbol(c) {
+                let position = Position::new(row, col);
+                symbol_positions.insert(position);
+            }
+        })
+    });
+    symbol_positions
+}
+
+fn get_gear_positions(lines: &[String]) -> HashSet<Position> {
+    let mut gear_positions = HashSet::<Position>::new();
+    lines.iter().enumerate().for_each(|(row, line)| {
+        line.chars().enumerate().for_each(|(col, c)| {
+            if c == '*' {
+                let position = Position::new(row, col);
+                gear_positions.insert(position);
+            }
+        })
+    });
+    gear_positions
+}
+
+fn get_part_numbers(row: usize, line: &str) -> Vec<PartNumber> {
+    let mut part_numbers = Vec::new();
+    let mut curr = 0;
+    let mut position = None;
+
+    for (col, c) in line.chars().enumerate() {
+        if let Some(d) = c.to_digit(10) {
+            if position.is_none() {
+                position = Some(Position::new(row, col));
+            }
+            curr = curr * 10 + d;
+        } else if curr != 0 {
+            part_numbers.push(PartNumber::new(
+                curr as usize,
+                position.as_ref().unwrap().clone(),
+            ));
+
+            curr = 0;
+            position = None;
+        }
+    }
+    // Numbers can run right up to the end of the line too
+    if let Some(position) = position {
+        part_numbers.push(PartNumber::new(curr as usize, position));
+    }
+
+    part_numbers
+}
@@ -0,0 +1,190 @@
+//! Day 4: Scratchcards
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    input::RawInput,
+    parse::Line,
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 4.
+pub struct Day4;
+
+/// The chosen numbers and winning numbers on a single card
+pub type Card = (Vec<u32>, Vec<u32>);
+
+impl Solver for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Self::parse_raw(&mut RawInput::from_lines(lines, "<lines>"))
+    }
+
+    fn parse_raw(input: &mut RawInput) -> Result<Self::Input, ParseError> {
+        input.parse_lines(parse_card)
+    }
+
+    fn part_one(cards: &Self::Input) -> Answer {
+        part1(cards)
+    }
+
+    fn part_two(cards: &Self::Input) -> Answer {
+        part2(cards)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+/// Makes up `size` cards, each with ten winning numbers and twenty-five chosen
+/// ones. Most cards win nothing and the rest only a few, so that the number of
+/// copies in part two stays manageable however many cards there are.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let width = size.to_string().len().max(3);
+    (1..=size)
+        .map(|card_num| {
+            let mut numbers = (1..=99).collect::<Vec<u32>>();
+            rng.shuffle(&mut numbers);
+            let (winning, rest) = numbers.split_at(10);
+            let matches = if rng.chance(3, 5) {
+                0
+            } else {
+                rng.range(1..=3) as usize
+            };
+            let mut chosen = winning[..matches].to_vec();
+            chosen.extend_from_slice(&rest[..25 - matches]);
+            rng.shuffle(&mut chosen);
+            let column = |numbers: &[u32]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            };
+            format!(
+                "Card {card_num:>width$}: {} | {}",
+                column(winning),
+                column(&chosen)
+            )
+        })
+        .collect()
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    reference_matches(lines)
+        .iter()
+        .map(|&matches| if matches == 0 { 0 } else { 1 << (matches - 1) })
+        .sum::<u32>()
+        .into()
+}
+
+/// Scratches every card and every copy won, one at a time.
+fn reference_part2(lines: &[String]) -> Value {
+    let matches = reference_matches(lines);
+    let mut unscratched = (0..matches.len()).collect::<Vec<usize>>();
+    let mut scratched = 0;
+    while let Some(card) = unscratched.pop() {
+        scratched += 1;
+        unscratched.extend(card + 1..=(card + matches[card]).min(matches.len() - 1));
+    }
+    scratched.into()
+}
+
+/// How many of each card's numbers are winning numbers.
+fn reference_matches(lines: &[String]) -> Vec<usize> {
+    lines
+        .iter()
+        .map(|line| {
+            let (winning, chosen) = line.split_once(':').unwrap().1.split_once('|').unwrap();
+            let winning = winning.split_whitespace().collect::<Vec<&str>>();
+            chosen
+                .split_whitespace()
+                .filter(|n| winning.contains(n))
+                .count()
+        })
+        .collect()
+}
+
+fn part1(cards: &[Card]) -> Answer {
+    let total_points = cards
+        .iter()
+        .map(|(chosen, winning)| {
+            chosen.iter().fold(0, |points, c| {
+                if winning.contains(c) {
+                    if points == 0 {
+                        1
+                    } else {
+                        points * 2
+                    }
+                } else {
+                    points
+                }
+            })
+        })
+        .sum::<u32>();
+    Answer::new("Total points", total_points)
+}
+
+fn part2(cards: &[Card]) -> Answer {
+    let max_card_num = cards.len();
+
+    let total_scratchcards = cards
+        .iter()
+        .enumerate()
+        .map(|(idx, cards)| (idx + 1, cards))
+        .fold(
+            HashMap::<usize, usize>::new(),
+            |mut scratchcards, (card_num, (chosen, winning))| {
+                // Get number of matches
+                let num_matches = chosen.iter().fold(0, |matches, c| {
+                    if winning.contains(c) {
+                        matches + 1
+                    } else {
+                        matches
+                    }
+                });
+                // Add scratchcards based on original
+                for num in card_num..=usize::min(card_num + num_matches, max_card_num) {
+                    scratchcards
+                        .entry(num)
+                        .and_modify(|count| {
+                            *count += 1;
+                        })
+                        .or_insert(1);
+                }
+                // Add scratchcards for each copy
+                for _ in 0..(scratchcards[&card_num] - 1) {
+                    for num in card_num + 1..=usize::min(card_num + num_matches, max_card_num) {
+                        scratchcards
+                            .entry(num)
+                            .and_modify(|count| {
+                                *count += 1;
+                            })
+                            .or_insert(1);
+                    }
+                }
+                scratchcards
+            },
+        )
+        .iter()
+        .fold(0, |total, (_, count)| total + count);
+    Answer::new("Total scratchcards", total_scratchcards)
+}
+
+fn parse_card(line: Line) -> Result<Card, ParseError> {
+    let points = line.split_once(line.text(), ':')?.1.trim();
+    let (chosen, winning) = line.split_once(points, '|')?;
+    Ok((line.parse_all(chosen)?, line.parse_all(winning)?))
+}
@@ -0,0 +1,362 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use crate::{
+    answer::{Answer, Value},
+    cancel,
+    error::ParseError,
+    parse,
+    rng::Rng,
+    solver::{Assumption, Generator, Reference, Solver},
+};
+
+/// The solver for day 5.
+pub struct Day5;
+
+impl Solver for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        get_almanac(&lines)
+    }
+
+    fn part_one(almanac: &Self::Input) -> Answer {
+        part1(almanac)
+    }
+
+    fn part_two(almanac: &Self::Input) -> Answer {
+        part2(almanac)
+    }
+
+    const ASSUMPTIONS: &'static [Assumption<Self::Input>] = &[
+        Assumption {
+            description:
+                "the seeds are followed by seven maps, from seed-to-soil to humidity-to-location",
+            check: has_seven_maps,
+        },
+        Assumption {
+            description: "the seeds are pairs of range starts and lengths",
+            check: has_seed_pairs,
+        },
+    ];
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Makes up ten seed ranges and seven maps over the numbers below
+/// `size * 10_000`. Each map cuts those numbers into a handful of ranges and
+/// shuffles them, so every number maps somewhere and nothing maps to the same
+/// place twice. Part two searches upwards from the lowest location, so its
+/// running time grows with `size`.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let limit = size as u64 * 10_000;
+    let seeds = (0..10)
+        .map(|_| {
+            let start = rng.below(limit);
+            let length = rng.range(1..=(limit / 20).max(1)).min(limit - start);
+            format!("{start} {length}")
+        })
+        .collect::<Vec<String>>();
+
+    let mut lines = vec![format!("seeds: {}", seeds.join(" "))];
+    for name in MAP_NAMES {
+        let mut cuts = (0..rng.range(7..=15))
+            .map(|_| rng.below(limit))
+            .chain([0, limit])
+            .collect::<Vec<u64>>();
+        cuts.sort_unstable();
+        cuts.dedup();
+        let mut ranges = cuts
+            .windows(2)
+            .map(|cut| (cut[0], cut[1] - cut[0]))
+            .collect::<Vec<(u64, u64)>>();
+        rng.shuffle(&mut ranges);
+
+        lines.push(String::new());
+        lines.push(format!("{name} map:"));
+        let mut dest_range_start = 0;
+        for (src_range_start, range_length) in ranges {
+            lines.push(format!(
+                "{dest_range_start} {src_range_start} {range_length}"
+            ));
+            dest_range_start += range_length;
+        }
+    }
+    lines
+}
+
+fn has_seven_maps(almanac: &Almanac) -> Result<(), String> {
+    match almanac.maps.len() {
+        7 => Ok(()),
+        n => Err(format!("expected 7 maps but found {n}")),
+    }
+}
+
+fn has_seed_pairs(almanac: &Almanac) -> Result<(), String> {
+    if almanac.seeds.len().is_multiple_of(2) {
+        Ok(())
+    } else {
+        Err(format!(
+            "found an odd number of seeds, {}",
+            almanac.seeds.len()
+        ))
+    }
+}
+
+/// The seeds to plant and the maps from seeds through to locations.
+pub struct Almanac {
+    /// The listed seeds, which are pairs of range starts and lengths in part two
+    pub seeds: Vec<u64>,
+    /// Each map's ranges, in the order they appear from seed-to-soil onwards
+    pub maps: Vec<Vec<MapRange>>,
+}
+
+/// A line of a map, sending `range_length` numbers from `src_range_start`
+/// onwards to the same offsets from `dest_range_start`.
+pub struct MapRange {
+    /// Where the range maps to
+    pub dest_range_start: u64,
+    /// Where the range maps from
+    pub src_range_start: u64,
+    /// How many numbers the range covers
+    pub range_length: u64,
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    let (seeds, maps) = reference_almanac(lines);
+    seeds
+        .iter()
+        .map(|&seed| reference_location(&maps, seed))
+        .min()
+        .unwrap()
+        .into()
+}
+
+/// Follows every single seed in every range through the maps.
+fn reference_part2(lines: &[String]) -> Value {
+    let (seeds, maps) = reference_almanac(lines);
+    seeds
+        .chunks(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(|seed| reference_location(&maps, seed))
+        .min()
+        .unwrap()
+        .into()
+}
+
+/// The seeds, and each map's lines as destination start, source start and
+/// length.
+fn reference_almanac(lines: &[String]) -> (Vec<u64>, Vec<Vec<[u64; 3]>>) {
+    let numbers = |line: &str| {
+        line.split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect::<Vec<u64>>()
+    };
+    let seeds = numbers(lines[0].split_once(':').unwrap().1);
+    let mut maps = Vec::<Vec<[u64; 3]>>::new();
+    for line in &lines[1..] {
+        if line.ends_with("map:") {
+            maps.push(Vec::new());
+        } else if let [dest, src, len] = numbers(line)[..] {
+            maps.last_mut().unwrap().push([dest, src, len]);
+        }
+    }
+    (seeds, maps)
+}
+
+fn reference_location(maps: &[Vec<[u64; 3]>], seed: u64) -> u64 {
+    maps.iter().fold(seed, |n, map| {
+        map.iter()
+            .find(|[_, src, len]| (*src..src + len).contains(&n))
+            .map_or(n, |[dest, src, _]| dest + (n - src))
+    })
+}
+
+fn part1(almanac: &Almanac) -> Answer {
+    let mut seeds = almanac
+        .seeds
+        .iter()
+        .map(|seed| (*seed, false))
+        .collect::<Vec<(u64, bool)>>();
+
+    for map in &almanac.maps {
+        seeds = seeds.iter().map(|(seed, _)| (*seed, false)).collect();
+        for range in map {
+            let dest_range_start = range.dest_range_start;
+            let src_range_start = range.src_range_start;
+            let src_range_end = src_range_start + range.range_length;
+
+            seeds = seeds
+                .iter()
+                .map(|(seed, seen)| {
+                    if !seen && *seed >= src_range_start && *seed < src_range_end {
+                        let inc = *seed - src_range_start;
+                        let dest = dest_range_start + inc;
+                        (dest, true)
+                    } else {
+                        (*seed, *seen)
+                    }
+                })
+                .collect();
+        }
+    }
+    Answer::new(
+        "Minimum location",
+        *seeds.iter().map(|(seed, _)| seed).min().unwrap(),
+    )
+}
+
+fn part2(almanac: &Almanac) -> Answer {
+    // Maybe I can work backwards? Nah... just testing numbers until I find the right one? Unless?
+    let seeds = get_seed_ranges(&almanac.seeds);
+
+    // Walk the maps backwards, from humidity-to-location down to seed-to-soil
+    let maps = almanac
+        .maps
+        .iter()
+        .rev()
+        .map(|map| {
+            map.iter()
+                .map(|range| {
+                    ReverseMap::new(
+                        range.dest_range_start,
+                        range.src_range_start,
+                        range.range_length,
+                    )
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<ReverseMap>>>();
+
+    for location in 0u64.. {
+        cancel::check();
+        let mut n = location;
+        for map in &maps {
+            if let Some(m) = map.iter().fold(None, |maybe, curr_map| {
+                if maybe.is_some() {
+                    maybe
+                } else {
+                    curr_map.map_if_contains(n)
+                }
+            }) {
+                n = m;
+            }
+        }
+        for seed in &seeds {
+            if seed.contains(n) {
+                return Answer::new("Location found", location);
+            }
+        }
+    }
+    unreachable!("every location is searched until one maps to a seed")
+}
+
+#[derive(Debug)]
+struct SeedRange {
+    start: u64,
+    end: u64,
+}
+
+impl SeedRange {
+    fn new(start: u64, len: u64) -> Self {
+        SeedRange {
+            start,
+            end: start + len,
+        }
+    }
+
+    fn contains(&self, n: u64) -> bool {
+        n >= self.start && n < self.end
+    }
+}
+
+#[derive(Debug)]
+struct ReverseMap {
+    from_start: u64,
+    from_end: u64,
+    to_start: u64,
+}
+
+impl ReverseMap {
+    fn new(from: u64, to: u64, len: u64) -> Self {
+        ReverseMap {
+            from_start: from,
+            from_end: from + len,
+            to_start: to,
+        }
+    }
+
+    fn map_if_contains(&self, n: u64) -> Option<u64> {
+        if n >= self.from_start && n < self.from_end {
+            let inc = n - self.from_start;
+            Some(self.to_start + inc)
+        } else {
+            None
+        }
+    }
+}
+
+fn get_almanac(lines: &[String]) -> Result<Almanac, ParseError> {
+    let seeds_line = parse::line(lines, 0)?;
+    let seeds = seeds_line.parse_all(seeds_line.split_once(seeds_line.text(), ':')?.1)?;
+
+    let mut maps = Vec::new();
+    for line in parse::lines(lines).skip(1) {
+        if line.text().contains("map") {
+            maps.push(Vec::new());
+        } else if !line.text().is_empty() {
+            let map = maps
+                .last_mut()
+                .ok_or_else(|| line.error(line.text(), "expected a map header first"))?;
+            let (dest_range_start, tail) = line.split_once(line.text(), ' ')?;
+            let (src_range_start, range_length) = line.split_once(tail.trim(), ' ')?;
+            map.push(MapRange {
+                dest_range_start: line.parse(dest_range_start)?,
+                src_range_start: line.parse(src_range_start)?,
+                range_length: line.parse(range_length)?,
+            });
+        }
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+fn get_seed_ranges(seeds: &[u64]) -> Vec<SeedRange> {
+    seeds
+        .iter()
+        .copied()
+        .enumerate()
+        .fold(
+            Vec::<Vec<u64>>::new(),
+            |mut seed_pairs, (idx, seed_or_range_len)| {
+                if idx % 2 == 0 {
+                    // Seed start
+                    seed_pairs.push(vec![seed_or_range_len]);
+                } else {
+                    // Range len
+                    seed_pairs.last_mut().unwrap().push(seed_or_range_len);
+                }
+                seed_pairs
+            },
+        )
+        .iter()
+        .fold(Vec::<SeedRange>::new(), |mut seeds, seed_range_pair| {
+            seeds.push(SeedRange::new(seed_range_pair[0], seed_range_pair[1]));
+            seeds
+        })
+}
@@ -0,0 +1,193 @@
+//! Day 6: Wait For It
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    parse::{self, Line},
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 6.
+pub struct Day6;
+
+impl Solver for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = RaceSheet;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Ok(RaceSheet {
+            races: get_races(&lines)?,
+            single_race: get_single_race(&lines)?,
+        })
+    }
+
+    fn part_one(sheet: &Self::Input) -> Answer {
+        part1(&sheet.races)
+    }
+
+    fn part_two(sheet: &Self::Input) -> Answer {
+        part2(&sheet.single_race)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+/// Makes up four races, as in the real input, each lasting up to `size`
+/// milliseconds and with a record that can be beaten. Part two joins every race
+/// into one long one, so no race lasts longer than 99 milliseconds to keep its
+/// numbers from overflowing.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let races = (0..4)
+        .map(|_| {
+            let time = rng.range(2..=size.clamp(2, 99) as u64);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.below(best))
+        })
+        .collect::<Vec<(u64, u64)>>();
+    let column = |numbers: Vec<u64>| {
+        numbers
+            .iter()
+            .map(|n| format!("{n:>6}"))
+            .collect::<String>()
+    };
+    vec![
+        format!(
+            "Time:    {}",
+            column(races.iter().map(|race| race.0).collect())
+        ),
+        format!(
+            "Distance:{}",
+            column(races.iter().map(|race| race.1).collect())
+        ),
+    ]
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    let numbers = |line: &str| {
+        line.split_once(':')
+            .unwrap()
+            .1
+            .split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect::<Vec<u64>>()
+    };
+    numbers(&lines[0])
+        .iter()
+        .zip(numbers(&lines[1]))
+        .map(|(&time, record)| reference_ways(time, record))
+        .product::<u64>()
+        .into()
+}
+
+fn reference_part2(lines: &[String]) -> Value {
+    let number = |line: &str| {
+        line.split_once(':')
+            .unwrap()
+            .1
+            .replace(' ', "")
+            .parse()
+            .unwrap()
+    };
+    reference_ways(number(&lines[0]), number(&lines[1])).into()
+}
+
+/// Tries holding the button for every possible time.
+fn reference_ways(time: u64, record: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| hold * (time - hold) > record)
+        .count() as u64
+}
+
+/// The same sheet read both ways: as several short races for part one, and as
+/// one long race with the spaces removed for part two.
+pub struct RaceSheet {
+    /// Each column as its own race
+    pub races: Vec<Race>,
+    /// Every column joined into one race
+    pub single_race: Race,
+}
+
+fn part1(races: &[Race]) -> Answer {
+    let answer = races
+        .iter()
+        .map(|race| race.number_ways_to_beat_record())
+        .product::<u64>();
+    Answer::new("Product of number of ways to beat each race", answer)
+}
+
+fn part2(race: &Race) -> Answer {
+    let answer = race.number_ways_to_beat_record();
+    Answer::new("Number of ways to beat longer race", answer)
+}
+
+/// A race and the distance to beat.
+pub struct Race {
+    /// How long the race lasts, in milliseconds
+    pub time: u64,
+    /// The record distance, in millimeters
+    pub record: u64,
+}
+
+impl Race {
+    fn new(time: u64, record: u64) -> Self {
+        Race { time, record }
+    }
+
+    /// How many whole milliseconds the button could be held for to win.
+    pub fn number_ways_to_beat_record(&self) -> u64 {
+        // calculate minimum time
+        let mut time_holding_button = 0;
+        let mut dist = time_holding_button * (self.time - time_holding_button);
+        while dist <= self.record {
+            time_holding_button += 1;
+            dist = time_holding_button * (self.time - time_holding_button);
+        }
+        let minimum_time_holding_button = time_holding_button;
+
+        // calculate max time
+        time_holding_button = self.time;
+        dist = time_holding_button * (self.time - time_holding_button);
+        while dist <= self.record {
+            time_holding_button -= 1;
+            dist = time_holding_button * (self.time - time_holding_button);
+        }
+        let maximum_time_holding_button = time_holding_button;
+
+        1 + maximum_time_holding_button - minimum_time_holding_button
+    }
+}
+
+fn get_single_race(lines: &[String]) -> Result<Race, ParseError> {
+    Ok(Race::new(
+        parse_single_num(parse::line(lines, 0)?)?,
+        parse_single_num(parse::line(lines, 1)?)?,
+    ))
+}
+
+fn parse_single_num(line: Line) -> Result<u64, ParseError> {
+    let numbers = line.split_once(line.text(), ':')?.1.trim();
+    let grouped_numbers = numbers
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+    str::parse::<u64>(&grouped_numbers).map_err(|_| line.error(numbers, "invalid number"))
+}
+
+fn get_races(lines: &[String]) -> Result<Vec<Race>, ParseError> {
+    Ok(parse_nums(parse::line(lines, 0)?)?
+        .iter()
+        .zip(&mut parse_nums(parse::line(lines, 1)?)?)
+        .map(|(time, record)| Race::new(*time, *record))
+        .collect())
+}
+
+fn parse_nums(line: Line) -> Result<Vec<u64>, ParseError> {
+    line.parse_all(line.split_once(line.text(), ':')?.1)
+}
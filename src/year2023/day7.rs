@@ -0,0 +1,393 @@
+//! Day 7: Camel Cards
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    parse::{self, Line},
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 7.
+pub struct Day7;
+
+impl Solver for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Hands;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Ok(Hands {
+            regular: parse::lines(&lines)
+                .map(Hand::from_str)
+                .collect::<Result<_, _>>()?,
+            joker: parse::lines(&lines)
+                .map(Hand::from_str)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(hands: &Self::Input) -> Answer {
+        part1(hands)
+    }
+
+    fn part_two(hands: &Self::Input) -> Answer {
+        part2(hands)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+
+    // Winnings used to be added up in a `u32`, which overflowed on large
+    // generated inputs
+    const VERSION: u32 = 2;
+}
+
+/// Makes up `size` different hands with bids of up to 1000, or as many as there
+/// are different hands if that's fewer.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut seen = HashSet::new();
+    let mut lines = Vec::new();
+    while lines.len() < size.min(CARDS.len().pow(5)) {
+        let hand = (0..5)
+            .map(|_| char::from(*rng.choose(CARDS)))
+            .collect::<String>();
+        if seen.insert(hand.clone()) {
+            lines.push(format!("{hand} {}", rng.range(1..=1000)));
+        }
+    }
+    lines
+}
+
+/// Every hand read with both sets of card rules, since `J` means something
+/// different in each part.
+pub struct Hands {
+    /// The hands with `J` as a jack, for part one
+    pub regular: Vec<Hand<RegularCard>>,
+    /// The hands with `J` as a joker, for part two
+    pub joker: Vec<Hand<JokerCard>>,
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    reference_winnings(lines, "23456789TJQKA", reference_counts)
+}
+
+fn reference_part2(lines: &[String]) -> Value {
+    reference_winnings(lines, "J23456789TQKA", reference_joker_counts)
+}
+
+/// Ranks the hands by how many of each card they have, largest first, which
+/// sorts in the same order as the hand types do, and then by each card's place
+/// in `order`.
+fn reference_winnings(lines: &[String], order: &str, counts: fn(&str) -> Vec<usize>) -> Value {
+    let mut hands = lines
+        .iter()
+        .map(|line| {
+            let (cards, bid) = line.split_once(' ').unwrap();
+            let strengths = cards
+                .chars()
+                .map(|c| order.find(c).unwrap())
+                .collect::<Vec<usize>>();
+            (counts(cards), strengths, bid.parse::<u64>().unwrap())
+        })
+        .collect::<Vec<_>>();
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, _, bid))| (idx as u64 + 1) * bid)
+        .sum::<u64>()
+        .into()
+}
+
+fn reference_counts(cards: &str) -> Vec<usize> {
+    let mut counts = BTreeMap::new();
+    for card in cards.chars() {
+        *counts.entry(card).or_insert(0) += 1;
+    }
+    let mut counts = counts.into_values().collect::<Vec<usize>>();
+    counts.sort_by(|a, b| b.cmp(a));
+    counts
+}
+
+/// Tries every card in place of each joker.
+fn reference_joker_counts(cards: &str) -> Vec<usize> {
+    match cards.find('J') {
+        Some(idx) => "23456789TQKA"
+            .chars()
+            .map(|card| {
+                let mut cards = cards.to_string();
+                cards.replace_range(idx..=idx, &card.to_string());
+                reference_joker_counts(&cards)
+            })
+            .max()
+            .unwrap(),
+        None => reference_counts(cards),
+    }
+}
+
+fn part1(hands: &Hands) -> Answer {
+    compute_winnings(&hands.regular)
+}
+
+fn part2(hands: &Hands) -> Answer {
+    compute_winnings(&hands.joker)
+}
+
+fn compute_winnings<C: Card + Eq + Hash + Ord + PartialEq + PartialOrd>(
+    hands: &[Hand<C>],
+) -> Answer {
+    let mut hands = hands.iter().collect::<Vec<&Hand<C>>>();
+    hands.sort();
+    let total_winnings = hands
+        .iter()
+        .enumerate()
+        .map(|(idx, hand)| (idx as u64 + 1) * hand.bid)
+        .sum::<u64>();
+    Answer::new("Total winnings", total_winnings)
+}
+
+/// A hand of five cards and its bid. Hands order by strength, which is their
+/// type and then their cards from first to last.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Hand<C>
+where
+    C: Card,
+{
+    /// The type of hand the cards make
+    pub hand_type: HandType,
+    /// The cards, in the order they were dealt
+    pub cards: Vec<C>,
+    /// What the hand wins for each place it ranks above last
+    pub bid: u64,
+}
+
+impl<C: Card + Eq + Hash + PartialEq> Hand<C> {
+    fn from_str(line: Line) -> Result<Self, ParseError> {
+        let (cards_str, bid_str) = line.split_once(line.text(), ' ')?;
+        if cards_str.len() != 5 {
+            return Err(line.error(cards_str, "expected a hand of 5 cards"));
+        }
+        let cards = cards_str
+            .char_indices()
+            .map(|(idx, c)| {
+                C::from_str(c).ok_or_else(|| {
+                    line.error(
+                        &cards_str[idx..idx + c.len_utf8()],
+                        format!("unknown card '{c}'"),
+                    )
+                })
+            })
+            .collect::<Result<Vec<C>, ParseError>>()?;
+        let bid = line.parse::<u64>(bid_str)?;
+        let hand_type = C::hand_type(&cards);
+        Ok(Hand {
+            cards,
+            bid,
+            hand_type,
+        })
+    }
+}
+
+/// The kinds of hand, from weakest to strongest.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HandType {
+    /// Every card is different
+    HighCard,
+    /// Two cards share a label
+    OnePair,
+    /// Two pairs of cards share a label
+    TwoPair,
+    /// Three cards share a label
+    ThreeOfAKind,
+    /// Three cards share a label and the other two share another
+    FullHouse,
+    /// Four cards share a label
+    FourOfAKind,
+    /// Every card shares a label
+    FiveOfAKind,
+}
+
+/// The rules for a set of cards, which differ between the parts.
+pub trait Card {
+    /// The card with the label `c`, if there is one.
+    fn from_str(c: char) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// The type of hand `cards` make.
+    fn hand_type(cards: &[Self]) -> HandType
+    where
+        Self: Sized;
+}
+
+/// Cards for part one, where `J` is a jack.
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RegularCard {
+    /// A number card from 2 to 10, where `T` is 10
+    Num(u32),
+    /// `J`
+    Jack,
+    /// `Q`
+    Queen,
+    /// `K`
+    King,
+    /// `A`
+    Ace,
+}
+
+impl Card for RegularCard {
+    fn from_str(c: char) -> Option<Self> {
+        match c {
+            '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
+                Some(RegularCard::Num(c.to_digit(10).unwrap()))
+            }
+            'T' => Some(RegularCard::Num(10)),
+            'J' => Some(RegularCard::Jack),
+            'Q' => Some(RegularCard::Queen),
+            'K' => Some(RegularCard::King),
+            'A' => Some(RegularCard::Ace),
+            _ => None,
+        }
+    }
+
+    fn hand_type(cards: &[RegularCard]) -> HandType {
+        let card_set = HashSet::<&RegularCard>::from_iter(cards.iter());
+        match card_set.len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                let first_card = &cards[0];
+                let mut count = 0;
+                for card in cards {
+                    if *first_card == *card {
+                        count += 1;
+                    }
+                }
+                if count == 1 || count == 4 {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                let mut counts = HashMap::<&RegularCard, u8>::new();
+                for card in cards {
+                    counts
+                        .entry(card)
+                        .and_modify(|count| *count += 1)
+                        .or_insert(1);
+                }
+                let mut hand_type = HandType::ThreeOfAKind;
+                for (_, count) in counts.iter() {
+                    if *count == 2 {
+                        hand_type = HandType::TwoPair;
+                        break;
+                    }
+                }
+                hand_type
+            }
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Cards for part two, where `J` is a joker that counts as whatever makes the
+/// strongest hand but is the weakest card on its own.
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum JokerCard {
+    /// `J`
+    Joker,
+    /// A number card from 2 to 10, where `T` is 10
+    Num(u32),
+    /// `Q`
+    Queen,
+    /// `K`
+    King,
+    /// `A`
+    Ace,
+}
+
+impl JokerCard {
+    fn as_regular_card(&self) -> RegularCard {
+        match self {
+            JokerCard::Num(i) => RegularCard::Num(*i),
+            JokerCard::Joker => RegularCard::Jack,
+            JokerCard::Queen => RegularCard::Queen,
+            JokerCard::King => RegularCard::King,
+            JokerCard::Ace => RegularCard::Ace,
+        }
+    }
+}
+
+impl Card for JokerCard {
+    fn from_str(c: char) -> Option<Self> {
+        match c {
+            'J' => Some(JokerCard::Joker),
+            '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
+                Some(JokerCard::Num(c.to_digit(10).unwrap()))
+            }
+            'T' => Some(JokerCard::Num(10)),
+            'Q' => Some(JokerCard::Queen),
+            'K' => Some(JokerCard::King),
+            'A' => Some(JokerCard::Ace),
+            _ => None,
+        }
+    }
+
+    fn hand_type(cards: &[JokerCard]) -> HandType {
+        let mut counts = HashMap::<&JokerCard, u8>::new();
+        for c in cards {
+            counts.entry(c).and_modify(|count| *count += 1).or_insert(1);
+        }
+        let joker_count = counts.get(&JokerCard::Joker).unwrap_or(&0);
+
+        /*
+        ZYXWV | ZZYXW | ZZYYX | ZZZYY | ZZZZY | ZZZZZ - No Joker -> whatever it is
+        ZYXWJ - 1 Joker, all diff -> 1 pair
+        ZZYXJ - 1 Joker, 1 pair -> 3 of a kind
+        ZZYYJ - 1 Joker, 2 pair -> full house
+        ZZZYJ - 1 Joker, 3 of a kind -> 4 of a kind
+        ZZZZJ - 1 Joker, 4 of a kind -> 5 of a kind
+        ZYXJJ - 2 Joker, 1 pair -> 3 of a kind
+        ZZYJJ - 2 Joker, 2 pair -> 4 of a kind
+        ZZZJJ - 2 Joker, full house -> 5 of a kind
+        ZYJJJ - 3 Joker, 3 of a kind -> 4 of a kind
+        ZZJJJ - 3 Joker, full house -> 5 of a kind
+        ZJJJJ - 4 Joker, 4 of a kind -> 5 of a kind
+        JJJJJ - 5 Joker, 5 of a kind
+        */
+        let regular_hand_type = RegularCard::hand_type(
+            &cards
+                .iter()
+                .map(|c| c.as_regular_card())
+                .collect::<Vec<RegularCard>>(),
+        );
+        match (joker_count, regular_hand_type) {
+            (0, regular_hand_type) => regular_hand_type,
+            (1, HandType::HighCard) => HandType::OnePair,
+            (1, HandType::OnePair) | (2, HandType::OnePair) => HandType::ThreeOfAKind,
+            (1, HandType::TwoPair) => HandType::FullHouse,
+            (1, HandType::ThreeOfAKind) | (2, HandType::TwoPair) | (3, HandType::ThreeOfAKind) => {
+                HandType::FourOfAKind
+            }
+            (1, HandType::FourOfAKind)
+            | (2, HandType::FullHouse)
+            | (3, HandType::FullHouse)
+            | (4, _)
+            | (5, _) => HandType::FiveOfAKind,
+            (count, t) => panic!("Unknown pairing found: {count} with {t:?}"),
+        }
+    }
+}
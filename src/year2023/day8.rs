@@ -0,0 +1,359 @@
+//! Day 8: Haunted Wasteland
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    answer::{Answer, Value},
+    cancel,
+    error::ParseError,
+    parse::{self, Line},
+    rng::Rng,
+    solver::{Assumption, Generator, Reference, Solver},
+};
+
+/// The solver for day 8.
+pub struct Day8;
+
+impl Solver for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Ok(Network {
+            directions: get_directions(parse::line(&lines, 0)?)?,
+            map: get_map(&lines)?,
+        })
+    }
+
+    fn part_one(network: &Self::Input) -> Answer {
+        part1(network)
+    }
+
+    fn part_two(network: &Self::Input) -> Answer {
+        part2(network)
+    }
+
+    const ASSUMPTIONS: &'static [Assumption<Self::Input>] = &[
+        Assumption {
+            description: "AAA leads to ZZZ",
+            check: reaches_zzz,
+        },
+        Assumption {
+            description:
+                "each ghost reaches the same Z again after as many steps as the first time",
+            check: ghosts_cycle,
+        },
+    ];
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+/// Makes up `size` instructions and a ghost for every four of them, up to six,
+/// AAA's among them. As in the real input, each ghost goes round a loop from its
+/// A node through its Z node that's a different prime number of times `size`
+/// steps long. Every step along
+/// a loop offers two nodes that both lead on to the next step, so the
+/// instructions pick which nodes are visited but not how far round a ghost is.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let directions = (0..size)
+        .map(|_| if rng.chance(1, 2) { 'L' } else { 'R' })
+        .collect::<String>();
+    let mut primes = vec![3, 5, 7, 11, 13, 17, 19];
+    rng.shuffle(&mut primes);
+    primes.truncate(size.div_ceil(4).clamp(1, 6));
+
+    // Longer names once there are too many nodes in between to name in three
+    // letters, which the parser doesn't mind
+    let count = primes.iter().map(|p| 2 * p * size).sum::<usize>();
+    let width = (3..)
+        .find(|&width| 26usize.pow(width - 1) * 24 >= 2 * count)
+        .unwrap();
+    let mut taken = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut name = |rng: &mut Rng, width: u32, last: &[u8]| loop {
+        let mut name = (1..width)
+            .map(|_| char::from(b'A' + rng.below(26) as u8))
+            .collect::<String>();
+        name.push(char::from(*rng.choose(last)));
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut nodes = Vec::new();
+    for (ghost, prime) in primes.iter().enumerate() {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (name(rng, 3, b"A"), name(rng, 3, b"Z"))
+        };
+        let mut steps = vec![vec![start, end.clone()]];
+        for _ in 1..prime * size {
+            steps.push(vec![
+                name(rng, width, b"BCDEFGHIJKLMNOPQRSTUVWXY"),
+                name(rng, width, b"BCDEFGHIJKLMNOPQRSTUVWXY"),
+            ]);
+        }
+        steps.push(vec![end]);
+        // The Z node carries on round the loop the same way as the A node
+        for (step, next) in steps.iter().zip(steps[1..].iter().chain([&steps[1]])) {
+            for node in step {
+                let mut elements = [&next[0], &next[next.len() - 1]];
+                rng.shuffle(&mut elements);
+                nodes.push(format!("{node} = ({}, {})", elements[0], elements[1]));
+            }
+        }
+    }
+    rng.shuffle(&mut nodes);
+
+    let mut lines = vec![directions, String::new()];
+    lines.extend(nodes);
+    lines
+}
+
+fn reaches_zzz(network: &Network) -> Result<(), String> {
+    if !network.map.contains_key("AAA") {
+        return Err("there is no AAA node".to_string());
+    }
+    // Part one stops at any Z, which is only right if that's ZZZ
+    match next_z(network, "AAA", 0) {
+        Some(("ZZZ", _)) => Ok(()),
+        Some((z, _)) => Err(format!("AAA reaches {z} first")),
+        None => Err("AAA never reaches a Z node".to_string()),
+    }
+}
+
+/// Part two takes the LCM of each ghost's steps to its first Z, which is only
+/// the answer if every ghost goes on to loop through that Z with that period.
+fn ghosts_cycle(network: &Network) -> Result<(), String> {
+    let mut starts = network
+        .map
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .collect::<Vec<&String>>();
+    starts.sort();
+    for start in starts {
+        let (z, first) = next_z(network, start, 0)
+            .ok_or_else(|| format!("the ghost from {start} never reaches a Z node"))?;
+        match next_z(network, z, first) {
+            Some((next, steps)) if next == z && steps == first => (),
+            Some((next, steps)) => {
+                return Err(format!(
+                    "the ghost from {start} reaches {z} after {first} steps, then {next} after {steps} more"
+                ))
+            }
+            None => return Err(format!("the ghost from {start} never leaves {z} for a Z node")),
+        }
+    }
+    Ok(())
+}
+
+/// Walks from `start`, beginning with the instruction at `offset`, to the next
+/// node ending in `Z`. Gives up once every pairing of node and instruction could
+/// have been visited, since the walk must be going round in circles by then.
+fn next_z<'a>(network: &'a Network, start: &'a str, offset: usize) -> Option<(&'a str, usize)> {
+    let mut node = start;
+    for step in 1..=network.directions.len() * network.map.len() {
+        cancel::check();
+        let direction = &network.directions[(offset + step - 1) % network.directions.len()];
+        node = network.map[node].get(direction);
+        if node.ends_with('Z') {
+            return Some((node, step));
+        }
+    }
+    None
+}
+
+/// The left/right instructions and the nodes they're followed through.
+pub struct Network {
+    /// The instructions, repeated as often as needed
+    pub directions: Vec<Direction>,
+    /// Where each node leads
+    pub map: HashMap<String, Elements>,
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    let (directions, nodes) = reference_network(lines);
+    let mut node = "AAA";
+    let mut steps = 0u64;
+    for direction in directions.iter().cycle() {
+        if node == "ZZZ" {
+            break;
+        }
+        node = reference_step(&nodes, node, *direction);
+        steps += 1;
+    }
+    steps.into()
+}
+
+/// Moves every ghost one step at a time until they're all on a Z node.
+fn reference_part2(lines: &[String]) -> Value {
+    let (directions, nodes) = reference_network(lines);
+    let mut ghosts = nodes
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .copied()
+        .collect::<Vec<&str>>();
+    let mut steps = 0u64;
+    for direction in directions.iter().cycle() {
+        if ghosts.iter().all(|node| node.ends_with('Z')) {
+            break;
+        }
+        for ghost in &mut ghosts {
+            *ghost = reference_step(&nodes, ghost, *direction);
+        }
+        steps += 1;
+    }
+    steps.into()
+}
+
+fn reference_network(lines: &[String]) -> (Vec<char>, HashMap<&str, (&str, &str)>) {
+    let nodes = lines[2..]
+        .iter()
+        .map(|line| {
+            let (node, elements) = line.split_once(" = ").unwrap();
+            let (left, right) = elements.trim_matches(['(', ')']).split_once(", ").unwrap();
+            (node, (left, right))
+        })
+        .collect();
+    (lines[0].chars().collect(), nodes)
+}
+
+fn reference_step<'a>(
+    nodes: &HashMap<&str, (&'a str, &'a str)>,
+    node: &str,
+    direction: char,
+) -> &'a str {
+    match direction {
+        'L' => nodes[node].0,
+        _ => nodes[node].1,
+    }
+}
+
+fn part1(network: &Network) -> Answer {
+    let step_count = steps(&network.directions, "AAA".to_string(), &network.map);
+    Answer::new("Steps to reach ZZZ", step_count)
+}
+
+fn part2(network: &Network) -> Answer {
+    let totals = network
+        .map
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(String::to_owned)
+        .map(|start| steps(&network.directions, start, &network.map))
+        .collect::<Vec<u64>>();
+    let answer = totals.iter().fold(1, |x, steps| lcm(x, *steps));
+    Answer::new("Steps for all to be on Z", answer)
+}
+
+/// How many steps it takes to get from `start` to a node ending in `Z`.
+pub fn steps(directions: &[Direction], start: String, map: &HashMap<String, Elements>) -> u64 {
+    let mut node = start;
+    let mut step_count = 0;
+    for direction in directions.iter().cycle() {
+        cancel::check();
+        if node.ends_with('Z') {
+            return step_count;
+        } else {
+            node = map.get(&node).unwrap().get(direction).to_string();
+            step_count += 1;
+        }
+    }
+    0
+}
+
+fn get_directions(line: Line) -> Result<Vec<Direction>, ParseError> {
+    line.text()
+        .char_indices()
+        .map(|(idx, c)| {
+            Direction::from_char(c).ok_or_else(|| {
+                line.error(
+                    &line.text()[idx..idx + c.len_utf8()],
+                    format!("unknown direction '{c}'"),
+                )
+            })
+        })
+        .collect()
+}
+
+fn get_map(lines: &[String]) -> Result<HashMap<String, Elements>, ParseError> {
+    let mut map = HashMap::new();
+    let mut references = Vec::new();
+    for line in parse::lines(lines).skip(2) {
+        let (key, nodes) = line.split_once(line.text(), '=')?;
+        let key = key.trim().to_string();
+        let (left, right) = line.split_once(nodes.trim(), ',')?;
+        let left = line.strip_prefix(left.trim(), '(')?;
+        let right = line.strip_suffix(right.trim(), ')')?;
+        references.push((line, left));
+        references.push((line, right));
+        map.insert(key, Elements::new(left.to_string(), right.to_string()));
+    }
+
+    // Make sure walking the network can never step onto a missing node
+    for (line, node) in references {
+        if !map.contains_key(node) {
+            return Err(line.error(node, format!("unknown node '{node}'")));
+        }
+    }
+    Ok(map)
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a * b / gcd(a, b)
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let tmp = a;
+        a = b;
+        b = tmp % b;
+    }
+    a
+}
+
+/// A single instruction.
+pub enum Direction {
+    /// Take the left element
+    Left,
+    /// Take the right element
+    Right,
+}
+
+impl Direction {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+/// The nodes reached from a node by going left or right.
+pub struct Elements {
+    /// The node to the left
+    pub left: String,
+    /// The node to the right
+    pub right: String,
+}
+
+impl Elements {
+    fn new(left: String, right: String) -> Self {
+        Elements { left, right }
+    }
+
+    /// The node reached by going `direction`.
+    pub fn get(&self, direction: &Direction) -> &str {
+        match direction {
+            Direction::Left => &self.left,
+            Direction::Right => &self.right,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Day 9: Mirage Maintenance
+
+use crate::{
+    answer::{Answer, Value},
+    error::ParseError,
+    input::RawInput,
+    parse::Line,
+    rng::Rng,
+    solver::{Generator, Reference, Solver},
+};
+
+/// The solver for day 9.
+pub struct Day9;
+
+impl Solver for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, ParseError> {
+        Self::parse_raw(&mut RawInput::from_lines(lines, "<lines>"))
+    }
+
+    fn parse_raw(input: &mut RawInput) -> Result<Self::Input, ParseError> {
+        input.parse_lines(to_vec)
+    }
+
+    fn part_one(histories: &Self::Input) -> Answer {
+        part1(histories)
+    }
+
+    fn part_two(histories: &Self::Input) -> Answer {
+        part2(histories)
+    }
+
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    const REFERENCE: Option<Reference> = Some(Reference {
+        part_one: reference_part1,
+        part_two: reference_part2,
+    });
+}
+
+/// Makes up `size` histories of 21 values, each a polynomial of up to the sixth
+/// degree, built up from a constant difference like the solution takes them
+/// apart.
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut values = vec![rng.range(0..=10) as i32 - 5; 21];
+            for _ in 0..rng.range(0..=6) {
+                values = values
+                    .iter()
+                    .scan(rng.range(0..=40) as i32 - 20, |value, diff| {
+                        let current = *value;
+                        *value += diff;
+                        Some(current)
+                    })
+                    .collect();
+            }
+            values
+                .iter()
+                .map(i32::to_string)
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+fn reference_part1(lines: &[String]) -> Value {
+    reference_histories(lines)
+        .iter()
+        .map(|values| reference_next(values))
+        .sum::<i64>()
+        .into()
+}
+
+/// Extrapolates each history reversed, so the next value is the one before.
+fn reference_part2(lines: &[String]) -> Value {
+    reference_histories(lines)
+        .into_iter()
+        .map(|mut values| {
+            values.reverse();
+            reference_next(&values)
+        })
+        .sum::<i64>()
+        .into()
+}
+
+fn reference_histories(lines: &[String]) -> Vec<Vec<i64>> {
+    lines
+        .iter()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|n| n.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+fn reference_next(values: &[i64]) -> i64 {
+    if values.iter().all(|value| *value == 0) {
+        return 0;
+    }
+    let diffs = values
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .collect::<Vec<i64>>();
+    values.last().unwrap() + reference_next(&diffs)
+}
+
+fn part1(histories: &[Vec<i32>]) -> Answer {
+    let mut answer = 0;
+    for values in histories {
+        answer += last_of_sequence_diffs(vec![*values.last().unwrap()], values)
+            .iter()
+            .sum::<i32>();
+    }
+    Answer::new("Sum of next sequence values", answer)
+}
+
+fn part2(histories: &[Vec<i32>]) -> Answer {
+    let mut answer = 0;
+    for values in histories {
+        answer += prev_of_sequence_diffs(vec![*values.first().unwrap()], values)
+            .iter()
+            .rev()
+            .fold(0, |prev_in_sequence, first| first - prev_in_sequence);
+    }
+    Answer::new("Sum of prev sequence values", answer)
+}
+
+fn to_vec(line: Line) -> Result<Vec<i32>, ParseError> {
+    let values = line.parse_all(line.text())?;
+    if values.is_empty() {
+        return Err(line.error(line.text(), "expected at least one value"));
+    }
+    Ok(values)
+}
+
+fn last_of_sequence_diffs(mut last_of_each_sequence: Vec<i32>, values: &[i32]) -> Vec<i32> {
+    if values.iter().all(|v| *v == 0) {
+        return last_of_each_sequence;
+    }
+    let diffs = values
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .collect::<Vec<i32>>();
+    if let Some(last) = diffs.last() {
+        last_of_each_sequence.push(*last);
+    }
+    last_of_sequence_diffs(last_of_each_sequence, &diffs)
+}
+
+fn prev_of_sequence_diffs(mut first_of_each_sequence: Vec<i32>, values: &[i32]) -> Vec<i32> {
+    if values.iter().all(|v| *v == 0) {
+        return first_of_each_sequence;
+    }
+    let diffs = values
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .collect::<Vec<i32>>();
+    if let Some(first) = diffs.first() {
+        first_of_each_sequence.push(*first);
+    }
+    prev_of_sequence_diffs(first_of_each_sequence, &diffs)
+}